@@ -4,6 +4,7 @@ use feather_util::{vec3, Position, Vec3d};
 use nbt::Value;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
 use thiserror::Error;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -43,6 +44,52 @@ impl<'a> From<&'a EntityData> for EntityDataKind {
     }
 }
 
+impl EntityDataKind {
+    /// Returns the kind of entity spawned by the given spawn egg,
+    /// or `None` if the item is not a spawn egg for a supported entity.
+    pub fn from_spawn_egg(item: Item) -> Option<Self> {
+        match item {
+            Item::CowSpawnEgg => Some(EntityDataKind::Cow),
+            Item::PigSpawnEgg => Some(EntityDataKind::Pig),
+            Item::ChickenSpawnEgg => Some(EntityDataKind::Chicken),
+            Item::SheepSpawnEgg => Some(EntityDataKind::Sheep),
+            Item::HorseSpawnEgg => Some(EntityDataKind::Horse),
+            Item::LlamaSpawnEgg => Some(EntityDataKind::Llama),
+            Item::MooshroomSpawnEgg => Some(EntityDataKind::Mooshroom),
+            Item::RabbitSpawnEgg => Some(EntityDataKind::Rabbit),
+            Item::SquidSpawnEgg => Some(EntityDataKind::Squid),
+            Item::DonkeySpawnEgg => Some(EntityDataKind::Donkey),
+            _ => None,
+        }
+    }
+
+    /// Returns a human-readable name for this kind,
+    /// for use in diagnostics, e.g. `cow`.
+    pub fn name(self) -> &'static str {
+        match self {
+            EntityDataKind::Item => "item",
+            EntityDataKind::Arrow => "arrow",
+            EntityDataKind::Cow => "cow",
+            EntityDataKind::Pig => "pig",
+            EntityDataKind::Chicken => "chicken",
+            EntityDataKind::Sheep => "sheep",
+            EntityDataKind::Horse => "horse",
+            EntityDataKind::Llama => "llama",
+            EntityDataKind::Mooshroom => "mooshroom",
+            EntityDataKind::Rabbit => "rabbit",
+            EntityDataKind::Squid => "squid",
+            EntityDataKind::Donkey => "donkey",
+            EntityDataKind::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for EntityDataKind {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.name())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "id")]
 pub enum EntityData {
@@ -339,4 +386,22 @@ mod tests {
         assert_eq!(data.read_position(), Ok(pos));
         assert_eq!(data.read_velocity(), Ok(vel));
     }
+
+    #[test]
+    fn test_from_spawn_egg() {
+        assert_eq!(
+            EntityDataKind::from_spawn_egg(Item::SheepSpawnEgg),
+            Some(EntityDataKind::Sheep)
+        );
+        assert_eq!(EntityDataKind::from_spawn_egg(Item::Stone), None);
+        // Spawn egg for an entity without `EntityData` support
+        assert_eq!(EntityDataKind::from_spawn_egg(Item::CreeperSpawnEgg), None);
+    }
+
+    #[test]
+    fn test_kind_name() {
+        assert_eq!(EntityDataKind::Mooshroom.name(), "mooshroom");
+        assert_eq!(EntityDataKind::Unknown.name(), "unknown");
+        assert_eq!(EntityDataKind::Cow.to_string(), "cow");
+    }
 }
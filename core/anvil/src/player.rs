@@ -1,6 +1,7 @@
 use crate::entity::BaseEntityData;
 use feather_inventory::{
-    SlotIndex, HOTBAR_SIZE, INVENTORY_SIZE, SLOT_ARMOR_MAX, SLOT_ARMOR_MIN, SLOT_HOTBAR_OFFSET,
+    Inventory, InventoryType, SlotIndex, CRAFTING_SIZE, HOTBAR_SIZE, INVENTORY_SIZE,
+    PLAYER_INVENTORY_SIZE, SLOT_ARMOR_MAX, SLOT_ARMOR_MIN, SLOT_HOTBAR_OFFSET,
     SLOT_INVENTORY_OFFSET, SLOT_OFFHAND,
 };
 use feather_items::{Item, ItemStack};
@@ -90,6 +91,38 @@ impl InventorySlot {
     }
 }
 
+/// Extension trait to convert a player `Inventory`
+/// to and from the slot list stored in a player data file.
+pub trait AnvilInventory {
+    /// Returns the occupied slots of this inventory using
+    /// Anvil slot numbering. Empty slots and crafting
+    /// slots are omitted, as they are not saved to disk.
+    fn to_anvil_slots(&self) -> Vec<InventorySlot>;
+
+    /// Creates a player inventory from a list of Anvil slots.
+    /// Slots with an unknown index are ignored.
+    fn from_anvil_slots(slots: &[InventorySlot]) -> Self;
+}
+
+impl AnvilInventory for Inventory {
+    fn to_anvil_slots(&self) -> Vec<InventorySlot> {
+        self.iter()
+            .filter(|(index, _)| *index >= CRAFTING_SIZE)
+            .map(|(index, stack)| InventorySlot::from_network_index(index, *stack))
+            .collect()
+    }
+
+    fn from_anvil_slots(slots: &[InventorySlot]) -> Self {
+        let mut inventory = Inventory::new(InventoryType::Player, PLAYER_INVENTORY_SIZE as u32);
+        for slot in slots {
+            if let Some(index) = slot.convert_index() {
+                inventory.set_item_at(index, slot.to_stack());
+            }
+        }
+        inventory
+    }
+}
+
 async fn load_from_file<R: AsyncRead + Unpin>(mut reader: R) -> Result<PlayerData, nbt::Error> {
     let mut buf = vec![];
     tokio::io::copy(&mut reader, &mut buf).await?;
@@ -168,6 +201,27 @@ mod tests {
         assert_eq!(item_stack.ty, Item::Air);
     }
 
+    #[test]
+    fn test_anvil_slots_round_trip() {
+        let mut inventory = Inventory::new(InventoryType::Player, PLAYER_INVENTORY_SIZE as u32);
+        inventory.set_item_at(SLOT_HOTBAR_OFFSET + 2, ItemStack::new(Item::Stone, 64));
+        inventory.set_item_at(20, ItemStack::new(Item::Feather, 3));
+        inventory.set_item_at(
+            feather_inventory::SLOT_ARMOR_HEAD,
+            ItemStack::new(Item::DiamondHelmet, 1),
+        );
+        inventory.set_item_at(SLOT_OFFHAND, ItemStack::new(Item::Shield, 1));
+
+        let slots = inventory.to_anvil_slots();
+        assert_eq!(slots.len(), 4);
+        let mut indices: Vec<i8> = slots.iter().map(|slot| slot.slot).collect();
+        indices.sort();
+        assert_eq!(indices, vec![-106, 2, 20, 103]);
+
+        let loaded = Inventory::from_anvil_slots(&slots);
+        assert_eq!(loaded.items(), inventory.items());
+    }
+
     #[test]
     fn test_convert_slot_index() {
         let mut map: HashMap<i8, usize> = HashMap::new();
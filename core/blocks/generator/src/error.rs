@@ -0,0 +1,24 @@
+//! Errors returned by the block generator.
+
+use std::io;
+use thiserror::Error;
+
+/// An error which occurred while generating block code.
+#[derive(Debug, Error)]
+pub enum GeneratorError {
+    /// The vanilla blocks report is not valid JSON
+    /// or does not match the expected structure.
+    #[error("failed to parse blocks report: {0}")]
+    Parse(#[from] serde_json::Error),
+    /// The blocks report was parsed but contains
+    /// data the generator cannot handle.
+    #[error("invalid blocks report: {0}")]
+    Validation(String),
+    /// Reading or writing a generated file failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    /// `rustfmt` could not be run or failed
+    /// to format a generated file.
+    #[error("failed to format generated code: {0}")]
+    Format(String),
+}
@@ -0,0 +1,76 @@
+//! Formatting of generated files.
+
+use crate::GeneratorError;
+use std::fs;
+use std::io;
+use std::process::Command;
+
+/// Formats the given file with `rustfmt`, then normalizes
+/// its line endings to LF.
+pub fn run_rustfmt(path: &str) -> Result<(), GeneratorError> {
+    let output = Command::new("rustfmt")
+        .arg(path)
+        .output()
+        .map_err(|e| rustfmt_error(path, e))?;
+
+    if !output.status.success() {
+        return Err(GeneratorError::Format(format!(
+            "rustfmt failed to format {}: {}",
+            path,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    normalize_line_endings(path)
+}
+
+/// Converts a failure to run `rustfmt` into an error.
+/// A missing binary comes with installation guidance.
+pub fn rustfmt_error(path: &str, e: io::Error) -> GeneratorError {
+    if e.kind() == io::ErrorKind::NotFound {
+        GeneratorError::Format(
+            "rustfmt was not found. Install it with `rustup component add rustfmt`".to_owned(),
+        )
+    } else {
+        GeneratorError::Format(format!("failed to run rustfmt on {}: {}", path, e))
+    }
+}
+
+/// Rewrites the given file with LF line endings. rustfmt may
+/// emit CRLF on Windows, which would churn the committed files.
+pub fn normalize_line_endings(path: &str) -> Result<(), GeneratorError> {
+    let contents = fs::read_to_string(path)?;
+    if contents.contains('\r') {
+        fs::write(path, contents.replace("\r\n", "\n"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rustfmt_not_found() {
+        let e = rustfmt_error("kind.rs", io::Error::from(io::ErrorKind::NotFound));
+        assert!(e.to_string().contains("rustup component add rustfmt"));
+
+        let e = rustfmt_error("kind.rs", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(!e.to_string().contains("rustup"));
+        assert!(e.to_string().contains("kind.rs"));
+    }
+
+    #[test]
+    fn normalize_crlf() {
+        let path = std::env::temp_dir().join("feather_blocks_generator_normalize_crlf.rs");
+        let path = path.to_str().unwrap();
+        fs::write(path, "pub enum BlockKind {\r\n    Air,\r\n}\r\n").unwrap();
+
+        normalize_line_endings(path).unwrap();
+
+        let contents = fs::read_to_string(path).unwrap();
+        fs::remove_file(path).unwrap();
+        assert_eq!(contents, "pub enum BlockKind {\n    Air,\n}\n");
+    }
+}
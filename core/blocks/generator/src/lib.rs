@@ -1,7 +1,7 @@
 use crate::load::ident;
 use heck::CamelCase;
 use heck::SnakeCase;
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::quote;
 use serde::ser::{SerializeSeq, SerializeStruct};
 use serde::{Serialize, Serializer};
@@ -10,8 +10,13 @@ use std::ops::RangeInclusive;
 use std::str::FromStr;
 use syn::export::ToTokens;
 
+mod error;
+mod format;
 mod load;
 
+pub use error::GeneratorError;
+pub use format::{normalize_line_endings, run_rustfmt, rustfmt_error};
+
 #[derive(Debug)]
 struct Blocks {
     property_types: BTreeMap<String, Property>,
@@ -242,7 +247,7 @@ pub struct Output {
 }
 
 /// Generates code for the block report.
-pub fn generate() -> anyhow::Result<Output> {
+pub fn generate() -> Result<Output, GeneratorError> {
     let blocks = load::load()?;
 
     let mut output = Output::default();
@@ -262,18 +267,50 @@ pub fn generate() -> anyhow::Result<Output> {
 /// Generates the `BlockKind` enum.
 fn generate_kind(blocks: &Blocks) -> TokenStream {
     let mut variants = vec![];
+    let mut translation_key_match_arms = vec![];
 
     for block in &blocks.blocks {
         let name = &block.name_camel_case;
         variants.push(quote! { #name });
+
+        let translation_key = format!("block.minecraft.{}", block.name);
+        translation_key_match_arms.push(quote! {
+            BlockKind::#name => #translation_key
+        });
     }
 
+    let count = Literal::usize_unsuffixed(blocks.blocks.len());
+
     quote! {
         #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ToPrimitive, FromPrimitive)]
         #[repr(u16)]
         pub enum BlockKind {
             #(#variants,)*
         }
+
+        impl BlockKind {
+            #[doc = "The number of block kinds."]
+            pub const COUNT: usize = #count;
+
+            #[doc = "Returns the translation key of this block kind, e.g. `block.minecraft.stone`."]
+            pub const fn translation_key(self) -> &'static str {
+                match self {
+                    #(#translation_key_match_arms,)*
+                }
+            }
+        }
+
+        #[doc = "Invokes the given closure once for every `BlockKind`, in ID order."]
+        #[doc = ""]
+        #[doc = "Since this expands to every variant, code built on it"]
+        #[doc = "automatically covers newly added blocks."]
+        #[macro_export]
+        macro_rules! for_each_block_kind {
+            ($f:expr) => {{
+                let mut f = $f;
+                #(f($crate::BlockKind::#variants);)*
+            }};
+        }
     }
 }
 
@@ -301,6 +338,8 @@ fn generate_table(blocks: &Blocks) -> TokenStream {
         );
         fns.push(quote! {
             #[doc = #doc]
+            #[inline]
+            #[must_use]
             pub fn #name(&self, kind: BlockKind, state: u16) -> Option<#property> {
                 let (offset_coefficient, stride) = self.#name[kind as u16 as usize];
 
@@ -319,6 +358,8 @@ fn generate_table(blocks: &Blocks) -> TokenStream {
         let to_u16 = property.tokens_for_to_u16(quote! { value });
         fns.push(quote! {
             #[doc = #doc]
+            #[inline]
+            #[must_use]
             pub fn #set(&self, kind: BlockKind, state: u16, value: #property) -> Option<u16> {
                 let (offset_coefficient, stride) = self.#name[kind as u16 as usize];
 
@@ -404,10 +445,13 @@ fn generate_block_fns(blocks: &Blocks) -> TokenStream {
         let with = ident(format!("with_{}", property_name));
 
         let f = quote! {
+            #[inline]
+            #[must_use]
             pub fn #property_name(self) -> Option<#property> {
                 BLOCK_TABLE.#property_name(self.kind, self.state)
             }
 
+            #[inline]
             pub fn #set(&mut self, #property_name: #property) -> bool {
                 match BLOCK_TABLE.#set(self.kind, self.state, #property_name) {
                     Some(new_state) => {
@@ -418,6 +462,8 @@ fn generate_block_fns(blocks: &Blocks) -> TokenStream {
                 }
             }
 
+            #[inline]
+            #[must_use]
             pub fn #with(mut self, #property_name: #property) -> Self {
                 self.#set(#property_name);
                 self
@@ -717,3 +763,74 @@ fn property_value_as_u16(value: &str, index: usize, kind: &PropertyKind) -> u16
         index as u16
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A single `furnace` block with a boolean `lit` property.
+    fn blocks() -> Blocks {
+        let lit = Property {
+            name: ident("lit"),
+            name_camel_case: ident("Lit"),
+            kind: PropertyKind::Boolean,
+            possible_values: vec!["true".to_owned(), "false".to_owned()],
+        };
+        let furnace = Block {
+            name: ident("furnace"),
+            name_camel_case: ident("Furnace"),
+            properties: vec!["lit".to_owned()],
+            default_state: vec![("lit".to_owned(), "false".to_owned())],
+            ids: vec![
+                (vec![("lit".to_owned(), "true".to_owned())], 1),
+                (vec![("lit".to_owned(), "false".to_owned())], 2),
+            ],
+            index_parameters: maplit::btreemap! { "lit".to_owned() => (2, 1) },
+        };
+
+        Blocks {
+            property_types: maplit::btreemap! { "lit".to_owned() => lit },
+            blocks: vec![furnace],
+        }
+    }
+
+    /// Returns the code with all whitespace removed.
+    fn compact(tokens: TokenStream) -> String {
+        tokens
+            .to_string()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect()
+    }
+
+    #[test]
+    fn accessors_are_inline() {
+        let blocks = blocks();
+
+        // `#[must_use]` is checked separately.
+        let table = compact(generate_table(&blocks)).replace("#[must_use]", "");
+        assert!(table.contains("#[inline]pubfnlit(&self"));
+        assert!(table.contains("#[inline]pubfnset_lit(&self"));
+
+        let block_fns = compact(generate_block_fns(&blocks)).replace("#[must_use]", "");
+        assert!(block_fns.contains("#[inline]pubfnlit(self"));
+        assert!(block_fns.contains("#[inline]pubfnset_lit(&mutself"));
+        assert!(block_fns.contains("#[inline]pubfnwith_lit(mutself"));
+    }
+
+    #[test]
+    fn getters_are_must_use() {
+        let blocks = blocks();
+
+        // `#[inline]` is checked separately.
+        let table = compact(generate_table(&blocks)).replace("#[inline]", "");
+        assert!(table.contains("#[must_use]pubfnlit(&self"));
+        assert!(table.contains("#[must_use]pubfnset_lit(&self"));
+
+        let block_fns = compact(generate_block_fns(&blocks)).replace("#[inline]", "");
+        assert!(block_fns.contains("#[must_use]pubfnlit(self"));
+        assert!(block_fns.contains("#[must_use]pubfnwith_lit(mutself"));
+        // Setters mutate in place, so their result may be ignored.
+        assert!(block_fns.contains("}pubfnset_lit(&mutself"));
+    }
+}
@@ -1,7 +1,7 @@
 //! Loads the vanilla blocks.json report into a `BlocksReport`, then
 //! converts this report into a `Blocks`.
 
-use crate::{Block, Blocks, Property, PropertyKind};
+use crate::{Block, Blocks, GeneratorError, Property, PropertyKind};
 use heck::CamelCase;
 use indexmap::map::IndexMap;
 use once_cell::sync::Lazy;
@@ -145,7 +145,7 @@ impl PropertyStore {
 }
 
 /// Parses the vanilla blocks report, returning a `Blocks`.
-pub(super) fn load() -> anyhow::Result<Blocks> {
+pub(super) fn load() -> Result<Blocks, GeneratorError> {
     let mut report = parse_report()?;
 
     let mut blocks = vec![];
@@ -226,7 +226,7 @@ fn fix_property_names(report: &mut BlocksReport) -> PropertyStore {
     store
 }
 
-fn load_block(identifier: &str, block: &BlockDefinition) -> anyhow::Result<Option<Block>> {
+fn load_block(identifier: &str, block: &BlockDefinition) -> Result<Option<Block>, GeneratorError> {
     let identifier = strip_prefix(identifier)?;
 
     let name_camel_case = identifier.to_camel_case();
@@ -340,11 +340,14 @@ fn fix_keywords(x: &str) -> &str {
 }
 
 /// Strips the minecraft: prefix from a block identifier.
-fn strip_prefix(x: &str) -> anyhow::Result<&str> {
+fn strip_prefix(x: &str) -> Result<&str, GeneratorError> {
     const PREFIX: &str = "minecraft:";
 
-    if x.len() <= PREFIX.len() {
-        anyhow::bail!("missing minecraft: prefix for block {}", x);
+    if !x.starts_with(PREFIX) || x.len() == PREFIX.len() {
+        return Err(GeneratorError::Validation(format!(
+            "missing minecraft: prefix for block {}",
+            x
+        )));
     }
 
     Ok(&x[PREFIX.len()..])
@@ -354,8 +357,25 @@ pub fn ident(x: impl AsRef<str>) -> Ident {
     Ident::new(x.as_ref(), Span::call_site()) // span doesn't matter as this is not a proc macro
 }
 
-fn parse_report() -> anyhow::Result<BlocksReport> {
+fn parse_report() -> Result<BlocksReport, GeneratorError> {
     let report = serde_json::from_slice(feather_data::minecraft::BLOCKS)?;
 
     Ok(report)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_prefix_validation_error() {
+        assert_eq!(strip_prefix("minecraft:stone").unwrap(), "stone");
+
+        for identifier in &["stone", "minecraft:", "mc:stone"] {
+            match strip_prefix(identifier) {
+                Err(GeneratorError::Validation(msg)) => assert!(msg.contains(identifier)),
+                x => panic!("expected validation error, got {:?}", x),
+            }
+        }
+    }
+}
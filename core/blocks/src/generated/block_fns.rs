@@ -5534,9 +5534,12 @@ impl BlockId {
         block.set_structure_block_mode(StructureBlockMode::Save);
         block
     }
+    #[inline]
+    #[must_use]
     pub fn age_0_15(self) -> Option<i32> {
         BLOCK_TABLE.age_0_15(self.kind, self.state)
     }
+    #[inline]
     pub fn set_age_0_15(&mut self, age_0_15: i32) -> bool {
         match BLOCK_TABLE.set_age_0_15(self.kind, self.state, age_0_15) {
             Some(new_state) => {
@@ -5546,13 +5549,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_age_0_15(mut self, age_0_15: i32) -> Self {
         self.set_age_0_15(age_0_15);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn age_0_2(self) -> Option<i32> {
         BLOCK_TABLE.age_0_2(self.kind, self.state)
     }
+    #[inline]
     pub fn set_age_0_2(&mut self, age_0_2: i32) -> bool {
         match BLOCK_TABLE.set_age_0_2(self.kind, self.state, age_0_2) {
             Some(new_state) => {
@@ -5562,13 +5570,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_age_0_2(mut self, age_0_2: i32) -> Self {
         self.set_age_0_2(age_0_2);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn age_0_25(self) -> Option<i32> {
         BLOCK_TABLE.age_0_25(self.kind, self.state)
     }
+    #[inline]
     pub fn set_age_0_25(&mut self, age_0_25: i32) -> bool {
         match BLOCK_TABLE.set_age_0_25(self.kind, self.state, age_0_25) {
             Some(new_state) => {
@@ -5578,13 +5591,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_age_0_25(mut self, age_0_25: i32) -> Self {
         self.set_age_0_25(age_0_25);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn age_0_3(self) -> Option<i32> {
         BLOCK_TABLE.age_0_3(self.kind, self.state)
     }
+    #[inline]
     pub fn set_age_0_3(&mut self, age_0_3: i32) -> bool {
         match BLOCK_TABLE.set_age_0_3(self.kind, self.state, age_0_3) {
             Some(new_state) => {
@@ -5594,13 +5612,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_age_0_3(mut self, age_0_3: i32) -> Self {
         self.set_age_0_3(age_0_3);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn age_0_5(self) -> Option<i32> {
         BLOCK_TABLE.age_0_5(self.kind, self.state)
     }
+    #[inline]
     pub fn set_age_0_5(&mut self, age_0_5: i32) -> bool {
         match BLOCK_TABLE.set_age_0_5(self.kind, self.state, age_0_5) {
             Some(new_state) => {
@@ -5610,13 +5633,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_age_0_5(mut self, age_0_5: i32) -> Self {
         self.set_age_0_5(age_0_5);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn age_0_7(self) -> Option<i32> {
         BLOCK_TABLE.age_0_7(self.kind, self.state)
     }
+    #[inline]
     pub fn set_age_0_7(&mut self, age_0_7: i32) -> bool {
         match BLOCK_TABLE.set_age_0_7(self.kind, self.state, age_0_7) {
             Some(new_state) => {
@@ -5626,13 +5654,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_age_0_7(mut self, age_0_7: i32) -> Self {
         self.set_age_0_7(age_0_7);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn attached(self) -> Option<bool> {
         BLOCK_TABLE.attached(self.kind, self.state)
     }
+    #[inline]
     pub fn set_attached(&mut self, attached: bool) -> bool {
         match BLOCK_TABLE.set_attached(self.kind, self.state, attached) {
             Some(new_state) => {
@@ -5642,13 +5675,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_attached(mut self, attached: bool) -> Self {
         self.set_attached(attached);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn axis_xyz(self) -> Option<AxisXyz> {
         BLOCK_TABLE.axis_xyz(self.kind, self.state)
     }
+    #[inline]
     pub fn set_axis_xyz(&mut self, axis_xyz: AxisXyz) -> bool {
         match BLOCK_TABLE.set_axis_xyz(self.kind, self.state, axis_xyz) {
             Some(new_state) => {
@@ -5658,13 +5696,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_axis_xyz(mut self, axis_xyz: AxisXyz) -> Self {
         self.set_axis_xyz(axis_xyz);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn axis_xz(self) -> Option<AxisXz> {
         BLOCK_TABLE.axis_xz(self.kind, self.state)
     }
+    #[inline]
     pub fn set_axis_xz(&mut self, axis_xz: AxisXz) -> bool {
         match BLOCK_TABLE.set_axis_xz(self.kind, self.state, axis_xz) {
             Some(new_state) => {
@@ -5674,13 +5717,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_axis_xz(mut self, axis_xz: AxisXz) -> Self {
         self.set_axis_xz(axis_xz);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn bites(self) -> Option<i32> {
         BLOCK_TABLE.bites(self.kind, self.state)
     }
+    #[inline]
     pub fn set_bites(&mut self, bites: i32) -> bool {
         match BLOCK_TABLE.set_bites(self.kind, self.state, bites) {
             Some(new_state) => {
@@ -5690,13 +5738,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_bites(mut self, bites: i32) -> Self {
         self.set_bites(bites);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn cauldron_level(self) -> Option<i32> {
         BLOCK_TABLE.cauldron_level(self.kind, self.state)
     }
+    #[inline]
     pub fn set_cauldron_level(&mut self, cauldron_level: i32) -> bool {
         match BLOCK_TABLE.set_cauldron_level(self.kind, self.state, cauldron_level) {
             Some(new_state) => {
@@ -5706,13 +5759,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_cauldron_level(mut self, cauldron_level: i32) -> Self {
         self.set_cauldron_level(cauldron_level);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn chest_kind(self) -> Option<ChestKind> {
         BLOCK_TABLE.chest_kind(self.kind, self.state)
     }
+    #[inline]
     pub fn set_chest_kind(&mut self, chest_kind: ChestKind) -> bool {
         match BLOCK_TABLE.set_chest_kind(self.kind, self.state, chest_kind) {
             Some(new_state) => {
@@ -5722,13 +5780,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_chest_kind(mut self, chest_kind: ChestKind) -> Self {
         self.set_chest_kind(chest_kind);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn comparator_mode(self) -> Option<ComparatorMode> {
         BLOCK_TABLE.comparator_mode(self.kind, self.state)
     }
+    #[inline]
     pub fn set_comparator_mode(&mut self, comparator_mode: ComparatorMode) -> bool {
         match BLOCK_TABLE.set_comparator_mode(self.kind, self.state, comparator_mode) {
             Some(new_state) => {
@@ -5738,13 +5801,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_comparator_mode(mut self, comparator_mode: ComparatorMode) -> Self {
         self.set_comparator_mode(comparator_mode);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn conditional(self) -> Option<bool> {
         BLOCK_TABLE.conditional(self.kind, self.state)
     }
+    #[inline]
     pub fn set_conditional(&mut self, conditional: bool) -> bool {
         match BLOCK_TABLE.set_conditional(self.kind, self.state, conditional) {
             Some(new_state) => {
@@ -5754,13 +5822,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_conditional(mut self, conditional: bool) -> Self {
         self.set_conditional(conditional);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn delay(self) -> Option<i32> {
         BLOCK_TABLE.delay(self.kind, self.state)
     }
+    #[inline]
     pub fn set_delay(&mut self, delay: i32) -> bool {
         match BLOCK_TABLE.set_delay(self.kind, self.state, delay) {
             Some(new_state) => {
@@ -5770,13 +5843,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_delay(mut self, delay: i32) -> Self {
         self.set_delay(delay);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn disarmed(self) -> Option<bool> {
         BLOCK_TABLE.disarmed(self.kind, self.state)
     }
+    #[inline]
     pub fn set_disarmed(&mut self, disarmed: bool) -> bool {
         match BLOCK_TABLE.set_disarmed(self.kind, self.state, disarmed) {
             Some(new_state) => {
@@ -5786,13 +5864,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_disarmed(mut self, disarmed: bool) -> Self {
         self.set_disarmed(disarmed);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn distance(self) -> Option<i32> {
         BLOCK_TABLE.distance(self.kind, self.state)
     }
+    #[inline]
     pub fn set_distance(&mut self, distance: i32) -> bool {
         match BLOCK_TABLE.set_distance(self.kind, self.state, distance) {
             Some(new_state) => {
@@ -5802,13 +5885,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_distance(mut self, distance: i32) -> Self {
         self.set_distance(distance);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn down(self) -> Option<bool> {
         BLOCK_TABLE.down(self.kind, self.state)
     }
+    #[inline]
     pub fn set_down(&mut self, down: bool) -> bool {
         match BLOCK_TABLE.set_down(self.kind, self.state, down) {
             Some(new_state) => {
@@ -5818,13 +5906,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_down(mut self, down: bool) -> Self {
         self.set_down(down);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn drag(self) -> Option<bool> {
         BLOCK_TABLE.drag(self.kind, self.state)
     }
+    #[inline]
     pub fn set_drag(&mut self, drag: bool) -> bool {
         match BLOCK_TABLE.set_drag(self.kind, self.state, drag) {
             Some(new_state) => {
@@ -5834,13 +5927,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_drag(mut self, drag: bool) -> Self {
         self.set_drag(drag);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn east_connected(self) -> Option<bool> {
         BLOCK_TABLE.east_connected(self.kind, self.state)
     }
+    #[inline]
     pub fn set_east_connected(&mut self, east_connected: bool) -> bool {
         match BLOCK_TABLE.set_east_connected(self.kind, self.state, east_connected) {
             Some(new_state) => {
@@ -5850,13 +5948,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_east_connected(mut self, east_connected: bool) -> Self {
         self.set_east_connected(east_connected);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn east_wire(self) -> Option<EastWire> {
         BLOCK_TABLE.east_wire(self.kind, self.state)
     }
+    #[inline]
     pub fn set_east_wire(&mut self, east_wire: EastWire) -> bool {
         match BLOCK_TABLE.set_east_wire(self.kind, self.state, east_wire) {
             Some(new_state) => {
@@ -5866,13 +5969,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_east_wire(mut self, east_wire: EastWire) -> Self {
         self.set_east_wire(east_wire);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn eggs(self) -> Option<i32> {
         BLOCK_TABLE.eggs(self.kind, self.state)
     }
+    #[inline]
     pub fn set_eggs(&mut self, eggs: i32) -> bool {
         match BLOCK_TABLE.set_eggs(self.kind, self.state, eggs) {
             Some(new_state) => {
@@ -5882,13 +5990,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_eggs(mut self, eggs: i32) -> Self {
         self.set_eggs(eggs);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn enabled(self) -> Option<bool> {
         BLOCK_TABLE.enabled(self.kind, self.state)
     }
+    #[inline]
     pub fn set_enabled(&mut self, enabled: bool) -> bool {
         match BLOCK_TABLE.set_enabled(self.kind, self.state, enabled) {
             Some(new_state) => {
@@ -5898,13 +6011,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_enabled(mut self, enabled: bool) -> Self {
         self.set_enabled(enabled);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn extended(self) -> Option<bool> {
         BLOCK_TABLE.extended(self.kind, self.state)
     }
+    #[inline]
     pub fn set_extended(&mut self, extended: bool) -> bool {
         match BLOCK_TABLE.set_extended(self.kind, self.state, extended) {
             Some(new_state) => {
@@ -5914,13 +6032,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_extended(mut self, extended: bool) -> Self {
         self.set_extended(extended);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn eye(self) -> Option<bool> {
         BLOCK_TABLE.eye(self.kind, self.state)
     }
+    #[inline]
     pub fn set_eye(&mut self, eye: bool) -> bool {
         match BLOCK_TABLE.set_eye(self.kind, self.state, eye) {
             Some(new_state) => {
@@ -5930,13 +6053,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_eye(mut self, eye: bool) -> Self {
         self.set_eye(eye);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn face(self) -> Option<Face> {
         BLOCK_TABLE.face(self.kind, self.state)
     }
+    #[inline]
     pub fn set_face(&mut self, face: Face) -> bool {
         match BLOCK_TABLE.set_face(self.kind, self.state, face) {
             Some(new_state) => {
@@ -5946,13 +6074,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_face(mut self, face: Face) -> Self {
         self.set_face(face);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn facing_cardinal(self) -> Option<FacingCardinal> {
         BLOCK_TABLE.facing_cardinal(self.kind, self.state)
     }
+    #[inline]
     pub fn set_facing_cardinal(&mut self, facing_cardinal: FacingCardinal) -> bool {
         match BLOCK_TABLE.set_facing_cardinal(self.kind, self.state, facing_cardinal) {
             Some(new_state) => {
@@ -5962,13 +6095,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_facing_cardinal(mut self, facing_cardinal: FacingCardinal) -> Self {
         self.set_facing_cardinal(facing_cardinal);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn facing_cardinal_and_down(self) -> Option<FacingCardinalAndDown> {
         BLOCK_TABLE.facing_cardinal_and_down(self.kind, self.state)
     }
+    #[inline]
     pub fn set_facing_cardinal_and_down(
         &mut self,
         facing_cardinal_and_down: FacingCardinalAndDown,
@@ -5985,6 +6123,8 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_facing_cardinal_and_down(
         mut self,
         facing_cardinal_and_down: FacingCardinalAndDown,
@@ -5992,9 +6132,12 @@ impl BlockId {
         self.set_facing_cardinal_and_down(facing_cardinal_and_down);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn facing_cubic(self) -> Option<FacingCubic> {
         BLOCK_TABLE.facing_cubic(self.kind, self.state)
     }
+    #[inline]
     pub fn set_facing_cubic(&mut self, facing_cubic: FacingCubic) -> bool {
         match BLOCK_TABLE.set_facing_cubic(self.kind, self.state, facing_cubic) {
             Some(new_state) => {
@@ -6004,13 +6147,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_facing_cubic(mut self, facing_cubic: FacingCubic) -> Self {
         self.set_facing_cubic(facing_cubic);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn half_top_bottom(self) -> Option<HalfTopBottom> {
         BLOCK_TABLE.half_top_bottom(self.kind, self.state)
     }
+    #[inline]
     pub fn set_half_top_bottom(&mut self, half_top_bottom: HalfTopBottom) -> bool {
         match BLOCK_TABLE.set_half_top_bottom(self.kind, self.state, half_top_bottom) {
             Some(new_state) => {
@@ -6020,13 +6168,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_half_top_bottom(mut self, half_top_bottom: HalfTopBottom) -> Self {
         self.set_half_top_bottom(half_top_bottom);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn half_upper_lower(self) -> Option<HalfUpperLower> {
         BLOCK_TABLE.half_upper_lower(self.kind, self.state)
     }
+    #[inline]
     pub fn set_half_upper_lower(&mut self, half_upper_lower: HalfUpperLower) -> bool {
         match BLOCK_TABLE.set_half_upper_lower(self.kind, self.state, half_upper_lower) {
             Some(new_state) => {
@@ -6036,13 +6189,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_half_upper_lower(mut self, half_upper_lower: HalfUpperLower) -> Self {
         self.set_half_upper_lower(half_upper_lower);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn has_bottle_0(self) -> Option<bool> {
         BLOCK_TABLE.has_bottle_0(self.kind, self.state)
     }
+    #[inline]
     pub fn set_has_bottle_0(&mut self, has_bottle_0: bool) -> bool {
         match BLOCK_TABLE.set_has_bottle_0(self.kind, self.state, has_bottle_0) {
             Some(new_state) => {
@@ -6052,13 +6210,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_has_bottle_0(mut self, has_bottle_0: bool) -> Self {
         self.set_has_bottle_0(has_bottle_0);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn has_bottle_1(self) -> Option<bool> {
         BLOCK_TABLE.has_bottle_1(self.kind, self.state)
     }
+    #[inline]
     pub fn set_has_bottle_1(&mut self, has_bottle_1: bool) -> bool {
         match BLOCK_TABLE.set_has_bottle_1(self.kind, self.state, has_bottle_1) {
             Some(new_state) => {
@@ -6068,13 +6231,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_has_bottle_1(mut self, has_bottle_1: bool) -> Self {
         self.set_has_bottle_1(has_bottle_1);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn has_bottle_2(self) -> Option<bool> {
         BLOCK_TABLE.has_bottle_2(self.kind, self.state)
     }
+    #[inline]
     pub fn set_has_bottle_2(&mut self, has_bottle_2: bool) -> bool {
         match BLOCK_TABLE.set_has_bottle_2(self.kind, self.state, has_bottle_2) {
             Some(new_state) => {
@@ -6084,13 +6252,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_has_bottle_2(mut self, has_bottle_2: bool) -> Self {
         self.set_has_bottle_2(has_bottle_2);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn has_record(self) -> Option<bool> {
         BLOCK_TABLE.has_record(self.kind, self.state)
     }
+    #[inline]
     pub fn set_has_record(&mut self, has_record: bool) -> bool {
         match BLOCK_TABLE.set_has_record(self.kind, self.state, has_record) {
             Some(new_state) => {
@@ -6100,13 +6273,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_has_record(mut self, has_record: bool) -> Self {
         self.set_has_record(has_record);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn hatch(self) -> Option<i32> {
         BLOCK_TABLE.hatch(self.kind, self.state)
     }
+    #[inline]
     pub fn set_hatch(&mut self, hatch: i32) -> bool {
         match BLOCK_TABLE.set_hatch(self.kind, self.state, hatch) {
             Some(new_state) => {
@@ -6116,13 +6294,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_hatch(mut self, hatch: i32) -> Self {
         self.set_hatch(hatch);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn hinge(self) -> Option<Hinge> {
         BLOCK_TABLE.hinge(self.kind, self.state)
     }
+    #[inline]
     pub fn set_hinge(&mut self, hinge: Hinge) -> bool {
         match BLOCK_TABLE.set_hinge(self.kind, self.state, hinge) {
             Some(new_state) => {
@@ -6132,13 +6315,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_hinge(mut self, hinge: Hinge) -> Self {
         self.set_hinge(hinge);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn in_wall(self) -> Option<bool> {
         BLOCK_TABLE.in_wall(self.kind, self.state)
     }
+    #[inline]
     pub fn set_in_wall(&mut self, in_wall: bool) -> bool {
         match BLOCK_TABLE.set_in_wall(self.kind, self.state, in_wall) {
             Some(new_state) => {
@@ -6148,13 +6336,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_in_wall(mut self, in_wall: bool) -> Self {
         self.set_in_wall(in_wall);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn instrument(self) -> Option<Instrument> {
         BLOCK_TABLE.instrument(self.kind, self.state)
     }
+    #[inline]
     pub fn set_instrument(&mut self, instrument: Instrument) -> bool {
         match BLOCK_TABLE.set_instrument(self.kind, self.state, instrument) {
             Some(new_state) => {
@@ -6164,13 +6357,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_instrument(mut self, instrument: Instrument) -> Self {
         self.set_instrument(instrument);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn inverted(self) -> Option<bool> {
         BLOCK_TABLE.inverted(self.kind, self.state)
     }
+    #[inline]
     pub fn set_inverted(&mut self, inverted: bool) -> bool {
         match BLOCK_TABLE.set_inverted(self.kind, self.state, inverted) {
             Some(new_state) => {
@@ -6180,13 +6378,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_inverted(mut self, inverted: bool) -> Self {
         self.set_inverted(inverted);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn layers(self) -> Option<i32> {
         BLOCK_TABLE.layers(self.kind, self.state)
     }
+    #[inline]
     pub fn set_layers(&mut self, layers: i32) -> bool {
         match BLOCK_TABLE.set_layers(self.kind, self.state, layers) {
             Some(new_state) => {
@@ -6196,13 +6399,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_layers(mut self, layers: i32) -> Self {
         self.set_layers(layers);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn lit(self) -> Option<bool> {
         BLOCK_TABLE.lit(self.kind, self.state)
     }
+    #[inline]
     pub fn set_lit(&mut self, lit: bool) -> bool {
         match BLOCK_TABLE.set_lit(self.kind, self.state, lit) {
             Some(new_state) => {
@@ -6212,13 +6420,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_lit(mut self, lit: bool) -> Self {
         self.set_lit(lit);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn locked(self) -> Option<bool> {
         BLOCK_TABLE.locked(self.kind, self.state)
     }
+    #[inline]
     pub fn set_locked(&mut self, locked: bool) -> bool {
         match BLOCK_TABLE.set_locked(self.kind, self.state, locked) {
             Some(new_state) => {
@@ -6228,13 +6441,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_locked(mut self, locked: bool) -> Self {
         self.set_locked(locked);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn moisture(self) -> Option<i32> {
         BLOCK_TABLE.moisture(self.kind, self.state)
     }
+    #[inline]
     pub fn set_moisture(&mut self, moisture: i32) -> bool {
         match BLOCK_TABLE.set_moisture(self.kind, self.state, moisture) {
             Some(new_state) => {
@@ -6244,13 +6462,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_moisture(mut self, moisture: i32) -> Self {
         self.set_moisture(moisture);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn north_connected(self) -> Option<bool> {
         BLOCK_TABLE.north_connected(self.kind, self.state)
     }
+    #[inline]
     pub fn set_north_connected(&mut self, north_connected: bool) -> bool {
         match BLOCK_TABLE.set_north_connected(self.kind, self.state, north_connected) {
             Some(new_state) => {
@@ -6260,13 +6483,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_north_connected(mut self, north_connected: bool) -> Self {
         self.set_north_connected(north_connected);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn north_wire(self) -> Option<NorthWire> {
         BLOCK_TABLE.north_wire(self.kind, self.state)
     }
+    #[inline]
     pub fn set_north_wire(&mut self, north_wire: NorthWire) -> bool {
         match BLOCK_TABLE.set_north_wire(self.kind, self.state, north_wire) {
             Some(new_state) => {
@@ -6276,13 +6504,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_north_wire(mut self, north_wire: NorthWire) -> Self {
         self.set_north_wire(north_wire);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn note(self) -> Option<i32> {
         BLOCK_TABLE.note(self.kind, self.state)
     }
+    #[inline]
     pub fn set_note(&mut self, note: i32) -> bool {
         match BLOCK_TABLE.set_note(self.kind, self.state, note) {
             Some(new_state) => {
@@ -6292,13 +6525,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_note(mut self, note: i32) -> Self {
         self.set_note(note);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn occupied(self) -> Option<bool> {
         BLOCK_TABLE.occupied(self.kind, self.state)
     }
+    #[inline]
     pub fn set_occupied(&mut self, occupied: bool) -> bool {
         match BLOCK_TABLE.set_occupied(self.kind, self.state, occupied) {
             Some(new_state) => {
@@ -6308,13 +6546,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_occupied(mut self, occupied: bool) -> Self {
         self.set_occupied(occupied);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn open(self) -> Option<bool> {
         BLOCK_TABLE.open(self.kind, self.state)
     }
+    #[inline]
     pub fn set_open(&mut self, open: bool) -> bool {
         match BLOCK_TABLE.set_open(self.kind, self.state, open) {
             Some(new_state) => {
@@ -6324,13 +6567,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_open(mut self, open: bool) -> Self {
         self.set_open(open);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn part(self) -> Option<Part> {
         BLOCK_TABLE.part(self.kind, self.state)
     }
+    #[inline]
     pub fn set_part(&mut self, part: Part) -> bool {
         match BLOCK_TABLE.set_part(self.kind, self.state, part) {
             Some(new_state) => {
@@ -6340,13 +6588,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_part(mut self, part: Part) -> Self {
         self.set_part(part);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn persistent(self) -> Option<bool> {
         BLOCK_TABLE.persistent(self.kind, self.state)
     }
+    #[inline]
     pub fn set_persistent(&mut self, persistent: bool) -> bool {
         match BLOCK_TABLE.set_persistent(self.kind, self.state, persistent) {
             Some(new_state) => {
@@ -6356,13 +6609,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_persistent(mut self, persistent: bool) -> Self {
         self.set_persistent(persistent);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn pickles(self) -> Option<i32> {
         BLOCK_TABLE.pickles(self.kind, self.state)
     }
+    #[inline]
     pub fn set_pickles(&mut self, pickles: i32) -> bool {
         match BLOCK_TABLE.set_pickles(self.kind, self.state, pickles) {
             Some(new_state) => {
@@ -6372,13 +6630,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_pickles(mut self, pickles: i32) -> Self {
         self.set_pickles(pickles);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn piston_kind(self) -> Option<PistonKind> {
         BLOCK_TABLE.piston_kind(self.kind, self.state)
     }
+    #[inline]
     pub fn set_piston_kind(&mut self, piston_kind: PistonKind) -> bool {
         match BLOCK_TABLE.set_piston_kind(self.kind, self.state, piston_kind) {
             Some(new_state) => {
@@ -6388,13 +6651,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_piston_kind(mut self, piston_kind: PistonKind) -> Self {
         self.set_piston_kind(piston_kind);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn power(self) -> Option<i32> {
         BLOCK_TABLE.power(self.kind, self.state)
     }
+    #[inline]
     pub fn set_power(&mut self, power: i32) -> bool {
         match BLOCK_TABLE.set_power(self.kind, self.state, power) {
             Some(new_state) => {
@@ -6404,13 +6672,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_power(mut self, power: i32) -> Self {
         self.set_power(power);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn powered(self) -> Option<bool> {
         BLOCK_TABLE.powered(self.kind, self.state)
     }
+    #[inline]
     pub fn set_powered(&mut self, powered: bool) -> bool {
         match BLOCK_TABLE.set_powered(self.kind, self.state, powered) {
             Some(new_state) => {
@@ -6420,13 +6693,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_powered(mut self, powered: bool) -> Self {
         self.set_powered(powered);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn powered_rail_shape(self) -> Option<PoweredRailShape> {
         BLOCK_TABLE.powered_rail_shape(self.kind, self.state)
     }
+    #[inline]
     pub fn set_powered_rail_shape(&mut self, powered_rail_shape: PoweredRailShape) -> bool {
         match BLOCK_TABLE.set_powered_rail_shape(self.kind, self.state, powered_rail_shape) {
             Some(new_state) => {
@@ -6436,13 +6714,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_powered_rail_shape(mut self, powered_rail_shape: PoweredRailShape) -> Self {
         self.set_powered_rail_shape(powered_rail_shape);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn rail_shape(self) -> Option<RailShape> {
         BLOCK_TABLE.rail_shape(self.kind, self.state)
     }
+    #[inline]
     pub fn set_rail_shape(&mut self, rail_shape: RailShape) -> bool {
         match BLOCK_TABLE.set_rail_shape(self.kind, self.state, rail_shape) {
             Some(new_state) => {
@@ -6452,13 +6735,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_rail_shape(mut self, rail_shape: RailShape) -> Self {
         self.set_rail_shape(rail_shape);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn rotation(self) -> Option<i32> {
         BLOCK_TABLE.rotation(self.kind, self.state)
     }
+    #[inline]
     pub fn set_rotation(&mut self, rotation: i32) -> bool {
         match BLOCK_TABLE.set_rotation(self.kind, self.state, rotation) {
             Some(new_state) => {
@@ -6468,13 +6756,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_rotation(mut self, rotation: i32) -> Self {
         self.set_rotation(rotation);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn short(self) -> Option<bool> {
         BLOCK_TABLE.short(self.kind, self.state)
     }
+    #[inline]
     pub fn set_short(&mut self, short: bool) -> bool {
         match BLOCK_TABLE.set_short(self.kind, self.state, short) {
             Some(new_state) => {
@@ -6484,13 +6777,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_short(mut self, short: bool) -> Self {
         self.set_short(short);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn slab_kind(self) -> Option<SlabKind> {
         BLOCK_TABLE.slab_kind(self.kind, self.state)
     }
+    #[inline]
     pub fn set_slab_kind(&mut self, slab_kind: SlabKind) -> bool {
         match BLOCK_TABLE.set_slab_kind(self.kind, self.state, slab_kind) {
             Some(new_state) => {
@@ -6500,13 +6798,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_slab_kind(mut self, slab_kind: SlabKind) -> Self {
         self.set_slab_kind(slab_kind);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn snowy(self) -> Option<bool> {
         BLOCK_TABLE.snowy(self.kind, self.state)
     }
+    #[inline]
     pub fn set_snowy(&mut self, snowy: bool) -> bool {
         match BLOCK_TABLE.set_snowy(self.kind, self.state, snowy) {
             Some(new_state) => {
@@ -6516,13 +6819,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_snowy(mut self, snowy: bool) -> Self {
         self.set_snowy(snowy);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn south_connected(self) -> Option<bool> {
         BLOCK_TABLE.south_connected(self.kind, self.state)
     }
+    #[inline]
     pub fn set_south_connected(&mut self, south_connected: bool) -> bool {
         match BLOCK_TABLE.set_south_connected(self.kind, self.state, south_connected) {
             Some(new_state) => {
@@ -6532,13 +6840,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_south_connected(mut self, south_connected: bool) -> Self {
         self.set_south_connected(south_connected);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn south_wire(self) -> Option<SouthWire> {
         BLOCK_TABLE.south_wire(self.kind, self.state)
     }
+    #[inline]
     pub fn set_south_wire(&mut self, south_wire: SouthWire) -> bool {
         match BLOCK_TABLE.set_south_wire(self.kind, self.state, south_wire) {
             Some(new_state) => {
@@ -6548,13 +6861,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_south_wire(mut self, south_wire: SouthWire) -> Self {
         self.set_south_wire(south_wire);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn stage(self) -> Option<i32> {
         BLOCK_TABLE.stage(self.kind, self.state)
     }
+    #[inline]
     pub fn set_stage(&mut self, stage: i32) -> bool {
         match BLOCK_TABLE.set_stage(self.kind, self.state, stage) {
             Some(new_state) => {
@@ -6564,13 +6882,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_stage(mut self, stage: i32) -> Self {
         self.set_stage(stage);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn stairs_shape(self) -> Option<StairsShape> {
         BLOCK_TABLE.stairs_shape(self.kind, self.state)
     }
+    #[inline]
     pub fn set_stairs_shape(&mut self, stairs_shape: StairsShape) -> bool {
         match BLOCK_TABLE.set_stairs_shape(self.kind, self.state, stairs_shape) {
             Some(new_state) => {
@@ -6580,13 +6903,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_stairs_shape(mut self, stairs_shape: StairsShape) -> Self {
         self.set_stairs_shape(stairs_shape);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn structure_block_mode(self) -> Option<StructureBlockMode> {
         BLOCK_TABLE.structure_block_mode(self.kind, self.state)
     }
+    #[inline]
     pub fn set_structure_block_mode(&mut self, structure_block_mode: StructureBlockMode) -> bool {
         match BLOCK_TABLE.set_structure_block_mode(self.kind, self.state, structure_block_mode) {
             Some(new_state) => {
@@ -6596,13 +6924,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_structure_block_mode(mut self, structure_block_mode: StructureBlockMode) -> Self {
         self.set_structure_block_mode(structure_block_mode);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn triggered(self) -> Option<bool> {
         BLOCK_TABLE.triggered(self.kind, self.state)
     }
+    #[inline]
     pub fn set_triggered(&mut self, triggered: bool) -> bool {
         match BLOCK_TABLE.set_triggered(self.kind, self.state, triggered) {
             Some(new_state) => {
@@ -6612,13 +6945,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_triggered(mut self, triggered: bool) -> Self {
         self.set_triggered(triggered);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn unstable(self) -> Option<bool> {
         BLOCK_TABLE.unstable(self.kind, self.state)
     }
+    #[inline]
     pub fn set_unstable(&mut self, unstable: bool) -> bool {
         match BLOCK_TABLE.set_unstable(self.kind, self.state, unstable) {
             Some(new_state) => {
@@ -6628,13 +6966,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_unstable(mut self, unstable: bool) -> Self {
         self.set_unstable(unstable);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn up(self) -> Option<bool> {
         BLOCK_TABLE.up(self.kind, self.state)
     }
+    #[inline]
     pub fn set_up(&mut self, up: bool) -> bool {
         match BLOCK_TABLE.set_up(self.kind, self.state, up) {
             Some(new_state) => {
@@ -6644,13 +6987,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_up(mut self, up: bool) -> Self {
         self.set_up(up);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn water_level(self) -> Option<i32> {
         BLOCK_TABLE.water_level(self.kind, self.state)
     }
+    #[inline]
     pub fn set_water_level(&mut self, water_level: i32) -> bool {
         match BLOCK_TABLE.set_water_level(self.kind, self.state, water_level) {
             Some(new_state) => {
@@ -6660,13 +7008,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_water_level(mut self, water_level: i32) -> Self {
         self.set_water_level(water_level);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn waterlogged(self) -> Option<bool> {
         BLOCK_TABLE.waterlogged(self.kind, self.state)
     }
+    #[inline]
     pub fn set_waterlogged(&mut self, waterlogged: bool) -> bool {
         match BLOCK_TABLE.set_waterlogged(self.kind, self.state, waterlogged) {
             Some(new_state) => {
@@ -6676,13 +7029,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_waterlogged(mut self, waterlogged: bool) -> Self {
         self.set_waterlogged(waterlogged);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn west_connected(self) -> Option<bool> {
         BLOCK_TABLE.west_connected(self.kind, self.state)
     }
+    #[inline]
     pub fn set_west_connected(&mut self, west_connected: bool) -> bool {
         match BLOCK_TABLE.set_west_connected(self.kind, self.state, west_connected) {
             Some(new_state) => {
@@ -6692,13 +7050,18 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_west_connected(mut self, west_connected: bool) -> Self {
         self.set_west_connected(west_connected);
         self
     }
+    #[inline]
+    #[must_use]
     pub fn west_wire(self) -> Option<WestWire> {
         BLOCK_TABLE.west_wire(self.kind, self.state)
     }
+    #[inline]
     pub fn set_west_wire(&mut self, west_wire: WestWire) -> bool {
         match BLOCK_TABLE.set_west_wire(self.kind, self.state, west_wire) {
             Some(new_state) => {
@@ -6708,6 +7071,8 @@ impl BlockId {
             None => false,
         }
     }
+    #[inline]
+    #[must_use]
     pub fn with_west_wire(mut self, west_wire: WestWire) -> Self {
         self.set_west_wire(west_wire);
         self
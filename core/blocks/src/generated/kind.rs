@@ -600,3 +600,1226 @@ pub enum BlockKind {
     BubbleColumn,
     StructureBlock,
 }
+impl BlockKind {
+    #[doc = "The number of block kinds."]
+    pub const COUNT: usize = 598;
+    #[doc = "Returns the translation key of this block kind, e.g. `block.minecraft.stone`."]
+    pub const fn translation_key(self) -> &'static str {
+        match self {
+            BlockKind::Air => "block.minecraft.air",
+            BlockKind::Stone => "block.minecraft.stone",
+            BlockKind::Granite => "block.minecraft.granite",
+            BlockKind::PolishedGranite => "block.minecraft.polished_granite",
+            BlockKind::Diorite => "block.minecraft.diorite",
+            BlockKind::PolishedDiorite => "block.minecraft.polished_diorite",
+            BlockKind::Andesite => "block.minecraft.andesite",
+            BlockKind::PolishedAndesite => "block.minecraft.polished_andesite",
+            BlockKind::GrassBlock => "block.minecraft.grass_block",
+            BlockKind::Dirt => "block.minecraft.dirt",
+            BlockKind::CoarseDirt => "block.minecraft.coarse_dirt",
+            BlockKind::Podzol => "block.minecraft.podzol",
+            BlockKind::Cobblestone => "block.minecraft.cobblestone",
+            BlockKind::OakPlanks => "block.minecraft.oak_planks",
+            BlockKind::SprucePlanks => "block.minecraft.spruce_planks",
+            BlockKind::BirchPlanks => "block.minecraft.birch_planks",
+            BlockKind::JunglePlanks => "block.minecraft.jungle_planks",
+            BlockKind::AcaciaPlanks => "block.minecraft.acacia_planks",
+            BlockKind::DarkOakPlanks => "block.minecraft.dark_oak_planks",
+            BlockKind::OakSapling => "block.minecraft.oak_sapling",
+            BlockKind::SpruceSapling => "block.minecraft.spruce_sapling",
+            BlockKind::BirchSapling => "block.minecraft.birch_sapling",
+            BlockKind::JungleSapling => "block.minecraft.jungle_sapling",
+            BlockKind::AcaciaSapling => "block.minecraft.acacia_sapling",
+            BlockKind::DarkOakSapling => "block.minecraft.dark_oak_sapling",
+            BlockKind::Bedrock => "block.minecraft.bedrock",
+            BlockKind::Water => "block.minecraft.water",
+            BlockKind::Lava => "block.minecraft.lava",
+            BlockKind::Sand => "block.minecraft.sand",
+            BlockKind::RedSand => "block.minecraft.red_sand",
+            BlockKind::Gravel => "block.minecraft.gravel",
+            BlockKind::GoldOre => "block.minecraft.gold_ore",
+            BlockKind::IronOre => "block.minecraft.iron_ore",
+            BlockKind::CoalOre => "block.minecraft.coal_ore",
+            BlockKind::OakLog => "block.minecraft.oak_log",
+            BlockKind::SpruceLog => "block.minecraft.spruce_log",
+            BlockKind::BirchLog => "block.minecraft.birch_log",
+            BlockKind::JungleLog => "block.minecraft.jungle_log",
+            BlockKind::AcaciaLog => "block.minecraft.acacia_log",
+            BlockKind::DarkOakLog => "block.minecraft.dark_oak_log",
+            BlockKind::StrippedSpruceLog => "block.minecraft.stripped_spruce_log",
+            BlockKind::StrippedBirchLog => "block.minecraft.stripped_birch_log",
+            BlockKind::StrippedJungleLog => "block.minecraft.stripped_jungle_log",
+            BlockKind::StrippedAcaciaLog => "block.minecraft.stripped_acacia_log",
+            BlockKind::StrippedDarkOakLog => "block.minecraft.stripped_dark_oak_log",
+            BlockKind::StrippedOakLog => "block.minecraft.stripped_oak_log",
+            BlockKind::OakWood => "block.minecraft.oak_wood",
+            BlockKind::SpruceWood => "block.minecraft.spruce_wood",
+            BlockKind::BirchWood => "block.minecraft.birch_wood",
+            BlockKind::JungleWood => "block.minecraft.jungle_wood",
+            BlockKind::AcaciaWood => "block.minecraft.acacia_wood",
+            BlockKind::DarkOakWood => "block.minecraft.dark_oak_wood",
+            BlockKind::StrippedOakWood => "block.minecraft.stripped_oak_wood",
+            BlockKind::StrippedSpruceWood => "block.minecraft.stripped_spruce_wood",
+            BlockKind::StrippedBirchWood => "block.minecraft.stripped_birch_wood",
+            BlockKind::StrippedJungleWood => "block.minecraft.stripped_jungle_wood",
+            BlockKind::StrippedAcaciaWood => "block.minecraft.stripped_acacia_wood",
+            BlockKind::StrippedDarkOakWood => "block.minecraft.stripped_dark_oak_wood",
+            BlockKind::OakLeaves => "block.minecraft.oak_leaves",
+            BlockKind::SpruceLeaves => "block.minecraft.spruce_leaves",
+            BlockKind::BirchLeaves => "block.minecraft.birch_leaves",
+            BlockKind::JungleLeaves => "block.minecraft.jungle_leaves",
+            BlockKind::AcaciaLeaves => "block.minecraft.acacia_leaves",
+            BlockKind::DarkOakLeaves => "block.minecraft.dark_oak_leaves",
+            BlockKind::Sponge => "block.minecraft.sponge",
+            BlockKind::WetSponge => "block.minecraft.wet_sponge",
+            BlockKind::Glass => "block.minecraft.glass",
+            BlockKind::LapisOre => "block.minecraft.lapis_ore",
+            BlockKind::LapisBlock => "block.minecraft.lapis_block",
+            BlockKind::Dispenser => "block.minecraft.dispenser",
+            BlockKind::Sandstone => "block.minecraft.sandstone",
+            BlockKind::ChiseledSandstone => "block.minecraft.chiseled_sandstone",
+            BlockKind::CutSandstone => "block.minecraft.cut_sandstone",
+            BlockKind::NoteBlock => "block.minecraft.note_block",
+            BlockKind::WhiteBed => "block.minecraft.white_bed",
+            BlockKind::OrangeBed => "block.minecraft.orange_bed",
+            BlockKind::MagentaBed => "block.minecraft.magenta_bed",
+            BlockKind::LightBlueBed => "block.minecraft.light_blue_bed",
+            BlockKind::YellowBed => "block.minecraft.yellow_bed",
+            BlockKind::LimeBed => "block.minecraft.lime_bed",
+            BlockKind::PinkBed => "block.minecraft.pink_bed",
+            BlockKind::GrayBed => "block.minecraft.gray_bed",
+            BlockKind::LightGrayBed => "block.minecraft.light_gray_bed",
+            BlockKind::CyanBed => "block.minecraft.cyan_bed",
+            BlockKind::PurpleBed => "block.minecraft.purple_bed",
+            BlockKind::BlueBed => "block.minecraft.blue_bed",
+            BlockKind::BrownBed => "block.minecraft.brown_bed",
+            BlockKind::GreenBed => "block.minecraft.green_bed",
+            BlockKind::RedBed => "block.minecraft.red_bed",
+            BlockKind::BlackBed => "block.minecraft.black_bed",
+            BlockKind::PoweredRail => "block.minecraft.powered_rail",
+            BlockKind::DetectorRail => "block.minecraft.detector_rail",
+            BlockKind::StickyPiston => "block.minecraft.sticky_piston",
+            BlockKind::Cobweb => "block.minecraft.cobweb",
+            BlockKind::Grass => "block.minecraft.grass",
+            BlockKind::Fern => "block.minecraft.fern",
+            BlockKind::DeadBush => "block.minecraft.dead_bush",
+            BlockKind::Seagrass => "block.minecraft.seagrass",
+            BlockKind::TallSeagrass => "block.minecraft.tall_seagrass",
+            BlockKind::Piston => "block.minecraft.piston",
+            BlockKind::PistonHead => "block.minecraft.piston_head",
+            BlockKind::WhiteWool => "block.minecraft.white_wool",
+            BlockKind::OrangeWool => "block.minecraft.orange_wool",
+            BlockKind::MagentaWool => "block.minecraft.magenta_wool",
+            BlockKind::LightBlueWool => "block.minecraft.light_blue_wool",
+            BlockKind::YellowWool => "block.minecraft.yellow_wool",
+            BlockKind::LimeWool => "block.minecraft.lime_wool",
+            BlockKind::PinkWool => "block.minecraft.pink_wool",
+            BlockKind::GrayWool => "block.minecraft.gray_wool",
+            BlockKind::LightGrayWool => "block.minecraft.light_gray_wool",
+            BlockKind::CyanWool => "block.minecraft.cyan_wool",
+            BlockKind::PurpleWool => "block.minecraft.purple_wool",
+            BlockKind::BlueWool => "block.minecraft.blue_wool",
+            BlockKind::BrownWool => "block.minecraft.brown_wool",
+            BlockKind::GreenWool => "block.minecraft.green_wool",
+            BlockKind::RedWool => "block.minecraft.red_wool",
+            BlockKind::BlackWool => "block.minecraft.black_wool",
+            BlockKind::MovingPiston => "block.minecraft.moving_piston",
+            BlockKind::Dandelion => "block.minecraft.dandelion",
+            BlockKind::Poppy => "block.minecraft.poppy",
+            BlockKind::BlueOrchid => "block.minecraft.blue_orchid",
+            BlockKind::Allium => "block.minecraft.allium",
+            BlockKind::AzureBluet => "block.minecraft.azure_bluet",
+            BlockKind::RedTulip => "block.minecraft.red_tulip",
+            BlockKind::OrangeTulip => "block.minecraft.orange_tulip",
+            BlockKind::WhiteTulip => "block.minecraft.white_tulip",
+            BlockKind::PinkTulip => "block.minecraft.pink_tulip",
+            BlockKind::OxeyeDaisy => "block.minecraft.oxeye_daisy",
+            BlockKind::BrownMushroom => "block.minecraft.brown_mushroom",
+            BlockKind::RedMushroom => "block.minecraft.red_mushroom",
+            BlockKind::GoldBlock => "block.minecraft.gold_block",
+            BlockKind::IronBlock => "block.minecraft.iron_block",
+            BlockKind::Bricks => "block.minecraft.bricks",
+            BlockKind::Tnt => "block.minecraft.tnt",
+            BlockKind::Bookshelf => "block.minecraft.bookshelf",
+            BlockKind::MossyCobblestone => "block.minecraft.mossy_cobblestone",
+            BlockKind::Obsidian => "block.minecraft.obsidian",
+            BlockKind::Torch => "block.minecraft.torch",
+            BlockKind::WallTorch => "block.minecraft.wall_torch",
+            BlockKind::Fire => "block.minecraft.fire",
+            BlockKind::Spawner => "block.minecraft.spawner",
+            BlockKind::OakStairs => "block.minecraft.oak_stairs",
+            BlockKind::Chest => "block.minecraft.chest",
+            BlockKind::RedstoneWire => "block.minecraft.redstone_wire",
+            BlockKind::DiamondOre => "block.minecraft.diamond_ore",
+            BlockKind::DiamondBlock => "block.minecraft.diamond_block",
+            BlockKind::CraftingTable => "block.minecraft.crafting_table",
+            BlockKind::Wheat => "block.minecraft.wheat",
+            BlockKind::Farmland => "block.minecraft.farmland",
+            BlockKind::Furnace => "block.minecraft.furnace",
+            BlockKind::Sign => "block.minecraft.sign",
+            BlockKind::OakDoor => "block.minecraft.oak_door",
+            BlockKind::Ladder => "block.minecraft.ladder",
+            BlockKind::Rail => "block.minecraft.rail",
+            BlockKind::CobblestoneStairs => "block.minecraft.cobblestone_stairs",
+            BlockKind::WallSign => "block.minecraft.wall_sign",
+            BlockKind::Lever => "block.minecraft.lever",
+            BlockKind::StonePressurePlate => "block.minecraft.stone_pressure_plate",
+            BlockKind::IronDoor => "block.minecraft.iron_door",
+            BlockKind::OakPressurePlate => "block.minecraft.oak_pressure_plate",
+            BlockKind::SprucePressurePlate => "block.minecraft.spruce_pressure_plate",
+            BlockKind::BirchPressurePlate => "block.minecraft.birch_pressure_plate",
+            BlockKind::JunglePressurePlate => "block.minecraft.jungle_pressure_plate",
+            BlockKind::AcaciaPressurePlate => "block.minecraft.acacia_pressure_plate",
+            BlockKind::DarkOakPressurePlate => "block.minecraft.dark_oak_pressure_plate",
+            BlockKind::RedstoneOre => "block.minecraft.redstone_ore",
+            BlockKind::RedstoneTorch => "block.minecraft.redstone_torch",
+            BlockKind::RedstoneWallTorch => "block.minecraft.redstone_wall_torch",
+            BlockKind::StoneButton => "block.minecraft.stone_button",
+            BlockKind::Snow => "block.minecraft.snow",
+            BlockKind::Ice => "block.minecraft.ice",
+            BlockKind::SnowBlock => "block.minecraft.snow_block",
+            BlockKind::Cactus => "block.minecraft.cactus",
+            BlockKind::Clay => "block.minecraft.clay",
+            BlockKind::SugarCane => "block.minecraft.sugar_cane",
+            BlockKind::Jukebox => "block.minecraft.jukebox",
+            BlockKind::OakFence => "block.minecraft.oak_fence",
+            BlockKind::Pumpkin => "block.minecraft.pumpkin",
+            BlockKind::Netherrack => "block.minecraft.netherrack",
+            BlockKind::SoulSand => "block.minecraft.soul_sand",
+            BlockKind::Glowstone => "block.minecraft.glowstone",
+            BlockKind::NetherPortal => "block.minecraft.nether_portal",
+            BlockKind::CarvedPumpkin => "block.minecraft.carved_pumpkin",
+            BlockKind::JackOLantern => "block.minecraft.jack_o_lantern",
+            BlockKind::Cake => "block.minecraft.cake",
+            BlockKind::Repeater => "block.minecraft.repeater",
+            BlockKind::WhiteStainedGlass => "block.minecraft.white_stained_glass",
+            BlockKind::OrangeStainedGlass => "block.minecraft.orange_stained_glass",
+            BlockKind::MagentaStainedGlass => "block.minecraft.magenta_stained_glass",
+            BlockKind::LightBlueStainedGlass => "block.minecraft.light_blue_stained_glass",
+            BlockKind::YellowStainedGlass => "block.minecraft.yellow_stained_glass",
+            BlockKind::LimeStainedGlass => "block.minecraft.lime_stained_glass",
+            BlockKind::PinkStainedGlass => "block.minecraft.pink_stained_glass",
+            BlockKind::GrayStainedGlass => "block.minecraft.gray_stained_glass",
+            BlockKind::LightGrayStainedGlass => "block.minecraft.light_gray_stained_glass",
+            BlockKind::CyanStainedGlass => "block.minecraft.cyan_stained_glass",
+            BlockKind::PurpleStainedGlass => "block.minecraft.purple_stained_glass",
+            BlockKind::BlueStainedGlass => "block.minecraft.blue_stained_glass",
+            BlockKind::BrownStainedGlass => "block.minecraft.brown_stained_glass",
+            BlockKind::GreenStainedGlass => "block.minecraft.green_stained_glass",
+            BlockKind::RedStainedGlass => "block.minecraft.red_stained_glass",
+            BlockKind::BlackStainedGlass => "block.minecraft.black_stained_glass",
+            BlockKind::OakTrapdoor => "block.minecraft.oak_trapdoor",
+            BlockKind::SpruceTrapdoor => "block.minecraft.spruce_trapdoor",
+            BlockKind::BirchTrapdoor => "block.minecraft.birch_trapdoor",
+            BlockKind::JungleTrapdoor => "block.minecraft.jungle_trapdoor",
+            BlockKind::AcaciaTrapdoor => "block.minecraft.acacia_trapdoor",
+            BlockKind::DarkOakTrapdoor => "block.minecraft.dark_oak_trapdoor",
+            BlockKind::InfestedStone => "block.minecraft.infested_stone",
+            BlockKind::InfestedCobblestone => "block.minecraft.infested_cobblestone",
+            BlockKind::InfestedStoneBricks => "block.minecraft.infested_stone_bricks",
+            BlockKind::InfestedMossyStoneBricks => "block.minecraft.infested_mossy_stone_bricks",
+            BlockKind::InfestedCrackedStoneBricks => {
+                "block.minecraft.infested_cracked_stone_bricks"
+            }
+            BlockKind::InfestedChiseledStoneBricks => {
+                "block.minecraft.infested_chiseled_stone_bricks"
+            }
+            BlockKind::StoneBricks => "block.minecraft.stone_bricks",
+            BlockKind::MossyStoneBricks => "block.minecraft.mossy_stone_bricks",
+            BlockKind::CrackedStoneBricks => "block.minecraft.cracked_stone_bricks",
+            BlockKind::ChiseledStoneBricks => "block.minecraft.chiseled_stone_bricks",
+            BlockKind::BrownMushroomBlock => "block.minecraft.brown_mushroom_block",
+            BlockKind::RedMushroomBlock => "block.minecraft.red_mushroom_block",
+            BlockKind::MushroomStem => "block.minecraft.mushroom_stem",
+            BlockKind::IronBars => "block.minecraft.iron_bars",
+            BlockKind::GlassPane => "block.minecraft.glass_pane",
+            BlockKind::Melon => "block.minecraft.melon",
+            BlockKind::AttachedPumpkinStem => "block.minecraft.attached_pumpkin_stem",
+            BlockKind::AttachedMelonStem => "block.minecraft.attached_melon_stem",
+            BlockKind::PumpkinStem => "block.minecraft.pumpkin_stem",
+            BlockKind::MelonStem => "block.minecraft.melon_stem",
+            BlockKind::Vine => "block.minecraft.vine",
+            BlockKind::OakFenceGate => "block.minecraft.oak_fence_gate",
+            BlockKind::BrickStairs => "block.minecraft.brick_stairs",
+            BlockKind::StoneBrickStairs => "block.minecraft.stone_brick_stairs",
+            BlockKind::Mycelium => "block.minecraft.mycelium",
+            BlockKind::LilyPad => "block.minecraft.lily_pad",
+            BlockKind::NetherBricks => "block.minecraft.nether_bricks",
+            BlockKind::NetherBrickFence => "block.minecraft.nether_brick_fence",
+            BlockKind::NetherBrickStairs => "block.minecraft.nether_brick_stairs",
+            BlockKind::NetherWart => "block.minecraft.nether_wart",
+            BlockKind::EnchantingTable => "block.minecraft.enchanting_table",
+            BlockKind::BrewingStand => "block.minecraft.brewing_stand",
+            BlockKind::Cauldron => "block.minecraft.cauldron",
+            BlockKind::EndPortal => "block.minecraft.end_portal",
+            BlockKind::EndPortalFrame => "block.minecraft.end_portal_frame",
+            BlockKind::EndStone => "block.minecraft.end_stone",
+            BlockKind::DragonEgg => "block.minecraft.dragon_egg",
+            BlockKind::RedstoneLamp => "block.minecraft.redstone_lamp",
+            BlockKind::Cocoa => "block.minecraft.cocoa",
+            BlockKind::SandstoneStairs => "block.minecraft.sandstone_stairs",
+            BlockKind::EmeraldOre => "block.minecraft.emerald_ore",
+            BlockKind::EnderChest => "block.minecraft.ender_chest",
+            BlockKind::TripwireHook => "block.minecraft.tripwire_hook",
+            BlockKind::Tripwire => "block.minecraft.tripwire",
+            BlockKind::EmeraldBlock => "block.minecraft.emerald_block",
+            BlockKind::SpruceStairs => "block.minecraft.spruce_stairs",
+            BlockKind::BirchStairs => "block.minecraft.birch_stairs",
+            BlockKind::JungleStairs => "block.minecraft.jungle_stairs",
+            BlockKind::CommandBlock => "block.minecraft.command_block",
+            BlockKind::Beacon => "block.minecraft.beacon",
+            BlockKind::CobblestoneWall => "block.minecraft.cobblestone_wall",
+            BlockKind::MossyCobblestoneWall => "block.minecraft.mossy_cobblestone_wall",
+            BlockKind::FlowerPot => "block.minecraft.flower_pot",
+            BlockKind::PottedOakSapling => "block.minecraft.potted_oak_sapling",
+            BlockKind::PottedSpruceSapling => "block.minecraft.potted_spruce_sapling",
+            BlockKind::PottedBirchSapling => "block.minecraft.potted_birch_sapling",
+            BlockKind::PottedJungleSapling => "block.minecraft.potted_jungle_sapling",
+            BlockKind::PottedAcaciaSapling => "block.minecraft.potted_acacia_sapling",
+            BlockKind::PottedDarkOakSapling => "block.minecraft.potted_dark_oak_sapling",
+            BlockKind::PottedFern => "block.minecraft.potted_fern",
+            BlockKind::PottedDandelion => "block.minecraft.potted_dandelion",
+            BlockKind::PottedPoppy => "block.minecraft.potted_poppy",
+            BlockKind::PottedBlueOrchid => "block.minecraft.potted_blue_orchid",
+            BlockKind::PottedAllium => "block.minecraft.potted_allium",
+            BlockKind::PottedAzureBluet => "block.minecraft.potted_azure_bluet",
+            BlockKind::PottedRedTulip => "block.minecraft.potted_red_tulip",
+            BlockKind::PottedOrangeTulip => "block.minecraft.potted_orange_tulip",
+            BlockKind::PottedWhiteTulip => "block.minecraft.potted_white_tulip",
+            BlockKind::PottedPinkTulip => "block.minecraft.potted_pink_tulip",
+            BlockKind::PottedOxeyeDaisy => "block.minecraft.potted_oxeye_daisy",
+            BlockKind::PottedRedMushroom => "block.minecraft.potted_red_mushroom",
+            BlockKind::PottedBrownMushroom => "block.minecraft.potted_brown_mushroom",
+            BlockKind::PottedDeadBush => "block.minecraft.potted_dead_bush",
+            BlockKind::PottedCactus => "block.minecraft.potted_cactus",
+            BlockKind::Carrots => "block.minecraft.carrots",
+            BlockKind::Potatoes => "block.minecraft.potatoes",
+            BlockKind::OakButton => "block.minecraft.oak_button",
+            BlockKind::SpruceButton => "block.minecraft.spruce_button",
+            BlockKind::BirchButton => "block.minecraft.birch_button",
+            BlockKind::JungleButton => "block.minecraft.jungle_button",
+            BlockKind::AcaciaButton => "block.minecraft.acacia_button",
+            BlockKind::DarkOakButton => "block.minecraft.dark_oak_button",
+            BlockKind::SkeletonWallSkull => "block.minecraft.skeleton_wall_skull",
+            BlockKind::SkeletonSkull => "block.minecraft.skeleton_skull",
+            BlockKind::WitherSkeletonWallSkull => "block.minecraft.wither_skeleton_wall_skull",
+            BlockKind::WitherSkeletonSkull => "block.minecraft.wither_skeleton_skull",
+            BlockKind::ZombieWallHead => "block.minecraft.zombie_wall_head",
+            BlockKind::ZombieHead => "block.minecraft.zombie_head",
+            BlockKind::PlayerWallHead => "block.minecraft.player_wall_head",
+            BlockKind::PlayerHead => "block.minecraft.player_head",
+            BlockKind::CreeperWallHead => "block.minecraft.creeper_wall_head",
+            BlockKind::CreeperHead => "block.minecraft.creeper_head",
+            BlockKind::DragonWallHead => "block.minecraft.dragon_wall_head",
+            BlockKind::DragonHead => "block.minecraft.dragon_head",
+            BlockKind::Anvil => "block.minecraft.anvil",
+            BlockKind::ChippedAnvil => "block.minecraft.chipped_anvil",
+            BlockKind::DamagedAnvil => "block.minecraft.damaged_anvil",
+            BlockKind::TrappedChest => "block.minecraft.trapped_chest",
+            BlockKind::LightWeightedPressurePlate => {
+                "block.minecraft.light_weighted_pressure_plate"
+            }
+            BlockKind::HeavyWeightedPressurePlate => {
+                "block.minecraft.heavy_weighted_pressure_plate"
+            }
+            BlockKind::Comparator => "block.minecraft.comparator",
+            BlockKind::DaylightDetector => "block.minecraft.daylight_detector",
+            BlockKind::RedstoneBlock => "block.minecraft.redstone_block",
+            BlockKind::NetherQuartzOre => "block.minecraft.nether_quartz_ore",
+            BlockKind::Hopper => "block.minecraft.hopper",
+            BlockKind::QuartzBlock => "block.minecraft.quartz_block",
+            BlockKind::ChiseledQuartzBlock => "block.minecraft.chiseled_quartz_block",
+            BlockKind::QuartzPillar => "block.minecraft.quartz_pillar",
+            BlockKind::QuartzStairs => "block.minecraft.quartz_stairs",
+            BlockKind::ActivatorRail => "block.minecraft.activator_rail",
+            BlockKind::Dropper => "block.minecraft.dropper",
+            BlockKind::WhiteTerracotta => "block.minecraft.white_terracotta",
+            BlockKind::OrangeTerracotta => "block.minecraft.orange_terracotta",
+            BlockKind::MagentaTerracotta => "block.minecraft.magenta_terracotta",
+            BlockKind::LightBlueTerracotta => "block.minecraft.light_blue_terracotta",
+            BlockKind::YellowTerracotta => "block.minecraft.yellow_terracotta",
+            BlockKind::LimeTerracotta => "block.minecraft.lime_terracotta",
+            BlockKind::PinkTerracotta => "block.minecraft.pink_terracotta",
+            BlockKind::GrayTerracotta => "block.minecraft.gray_terracotta",
+            BlockKind::LightGrayTerracotta => "block.minecraft.light_gray_terracotta",
+            BlockKind::CyanTerracotta => "block.minecraft.cyan_terracotta",
+            BlockKind::PurpleTerracotta => "block.minecraft.purple_terracotta",
+            BlockKind::BlueTerracotta => "block.minecraft.blue_terracotta",
+            BlockKind::BrownTerracotta => "block.minecraft.brown_terracotta",
+            BlockKind::GreenTerracotta => "block.minecraft.green_terracotta",
+            BlockKind::RedTerracotta => "block.minecraft.red_terracotta",
+            BlockKind::BlackTerracotta => "block.minecraft.black_terracotta",
+            BlockKind::WhiteStainedGlassPane => "block.minecraft.white_stained_glass_pane",
+            BlockKind::OrangeStainedGlassPane => "block.minecraft.orange_stained_glass_pane",
+            BlockKind::MagentaStainedGlassPane => "block.minecraft.magenta_stained_glass_pane",
+            BlockKind::LightBlueStainedGlassPane => "block.minecraft.light_blue_stained_glass_pane",
+            BlockKind::YellowStainedGlassPane => "block.minecraft.yellow_stained_glass_pane",
+            BlockKind::LimeStainedGlassPane => "block.minecraft.lime_stained_glass_pane",
+            BlockKind::PinkStainedGlassPane => "block.minecraft.pink_stained_glass_pane",
+            BlockKind::GrayStainedGlassPane => "block.minecraft.gray_stained_glass_pane",
+            BlockKind::LightGrayStainedGlassPane => "block.minecraft.light_gray_stained_glass_pane",
+            BlockKind::CyanStainedGlassPane => "block.minecraft.cyan_stained_glass_pane",
+            BlockKind::PurpleStainedGlassPane => "block.minecraft.purple_stained_glass_pane",
+            BlockKind::BlueStainedGlassPane => "block.minecraft.blue_stained_glass_pane",
+            BlockKind::BrownStainedGlassPane => "block.minecraft.brown_stained_glass_pane",
+            BlockKind::GreenStainedGlassPane => "block.minecraft.green_stained_glass_pane",
+            BlockKind::RedStainedGlassPane => "block.minecraft.red_stained_glass_pane",
+            BlockKind::BlackStainedGlassPane => "block.minecraft.black_stained_glass_pane",
+            BlockKind::AcaciaStairs => "block.minecraft.acacia_stairs",
+            BlockKind::DarkOakStairs => "block.minecraft.dark_oak_stairs",
+            BlockKind::SlimeBlock => "block.minecraft.slime_block",
+            BlockKind::Barrier => "block.minecraft.barrier",
+            BlockKind::IronTrapdoor => "block.minecraft.iron_trapdoor",
+            BlockKind::Prismarine => "block.minecraft.prismarine",
+            BlockKind::PrismarineBricks => "block.minecraft.prismarine_bricks",
+            BlockKind::DarkPrismarine => "block.minecraft.dark_prismarine",
+            BlockKind::PrismarineStairs => "block.minecraft.prismarine_stairs",
+            BlockKind::PrismarineBrickStairs => "block.minecraft.prismarine_brick_stairs",
+            BlockKind::DarkPrismarineStairs => "block.minecraft.dark_prismarine_stairs",
+            BlockKind::PrismarineSlab => "block.minecraft.prismarine_slab",
+            BlockKind::PrismarineBrickSlab => "block.minecraft.prismarine_brick_slab",
+            BlockKind::DarkPrismarineSlab => "block.minecraft.dark_prismarine_slab",
+            BlockKind::SeaLantern => "block.minecraft.sea_lantern",
+            BlockKind::HayBlock => "block.minecraft.hay_block",
+            BlockKind::WhiteCarpet => "block.minecraft.white_carpet",
+            BlockKind::OrangeCarpet => "block.minecraft.orange_carpet",
+            BlockKind::MagentaCarpet => "block.minecraft.magenta_carpet",
+            BlockKind::LightBlueCarpet => "block.minecraft.light_blue_carpet",
+            BlockKind::YellowCarpet => "block.minecraft.yellow_carpet",
+            BlockKind::LimeCarpet => "block.minecraft.lime_carpet",
+            BlockKind::PinkCarpet => "block.minecraft.pink_carpet",
+            BlockKind::GrayCarpet => "block.minecraft.gray_carpet",
+            BlockKind::LightGrayCarpet => "block.minecraft.light_gray_carpet",
+            BlockKind::CyanCarpet => "block.minecraft.cyan_carpet",
+            BlockKind::PurpleCarpet => "block.minecraft.purple_carpet",
+            BlockKind::BlueCarpet => "block.minecraft.blue_carpet",
+            BlockKind::BrownCarpet => "block.minecraft.brown_carpet",
+            BlockKind::GreenCarpet => "block.minecraft.green_carpet",
+            BlockKind::RedCarpet => "block.minecraft.red_carpet",
+            BlockKind::BlackCarpet => "block.minecraft.black_carpet",
+            BlockKind::Terracotta => "block.minecraft.terracotta",
+            BlockKind::CoalBlock => "block.minecraft.coal_block",
+            BlockKind::PackedIce => "block.minecraft.packed_ice",
+            BlockKind::Sunflower => "block.minecraft.sunflower",
+            BlockKind::Lilac => "block.minecraft.lilac",
+            BlockKind::RoseBush => "block.minecraft.rose_bush",
+            BlockKind::Peony => "block.minecraft.peony",
+            BlockKind::TallGrass => "block.minecraft.tall_grass",
+            BlockKind::LargeFern => "block.minecraft.large_fern",
+            BlockKind::WhiteBanner => "block.minecraft.white_banner",
+            BlockKind::OrangeBanner => "block.minecraft.orange_banner",
+            BlockKind::MagentaBanner => "block.minecraft.magenta_banner",
+            BlockKind::LightBlueBanner => "block.minecraft.light_blue_banner",
+            BlockKind::YellowBanner => "block.minecraft.yellow_banner",
+            BlockKind::LimeBanner => "block.minecraft.lime_banner",
+            BlockKind::PinkBanner => "block.minecraft.pink_banner",
+            BlockKind::GrayBanner => "block.minecraft.gray_banner",
+            BlockKind::LightGrayBanner => "block.minecraft.light_gray_banner",
+            BlockKind::CyanBanner => "block.minecraft.cyan_banner",
+            BlockKind::PurpleBanner => "block.minecraft.purple_banner",
+            BlockKind::BlueBanner => "block.minecraft.blue_banner",
+            BlockKind::BrownBanner => "block.minecraft.brown_banner",
+            BlockKind::GreenBanner => "block.minecraft.green_banner",
+            BlockKind::RedBanner => "block.minecraft.red_banner",
+            BlockKind::BlackBanner => "block.minecraft.black_banner",
+            BlockKind::WhiteWallBanner => "block.minecraft.white_wall_banner",
+            BlockKind::OrangeWallBanner => "block.minecraft.orange_wall_banner",
+            BlockKind::MagentaWallBanner => "block.minecraft.magenta_wall_banner",
+            BlockKind::LightBlueWallBanner => "block.minecraft.light_blue_wall_banner",
+            BlockKind::YellowWallBanner => "block.minecraft.yellow_wall_banner",
+            BlockKind::LimeWallBanner => "block.minecraft.lime_wall_banner",
+            BlockKind::PinkWallBanner => "block.minecraft.pink_wall_banner",
+            BlockKind::GrayWallBanner => "block.minecraft.gray_wall_banner",
+            BlockKind::LightGrayWallBanner => "block.minecraft.light_gray_wall_banner",
+            BlockKind::CyanWallBanner => "block.minecraft.cyan_wall_banner",
+            BlockKind::PurpleWallBanner => "block.minecraft.purple_wall_banner",
+            BlockKind::BlueWallBanner => "block.minecraft.blue_wall_banner",
+            BlockKind::BrownWallBanner => "block.minecraft.brown_wall_banner",
+            BlockKind::GreenWallBanner => "block.minecraft.green_wall_banner",
+            BlockKind::RedWallBanner => "block.minecraft.red_wall_banner",
+            BlockKind::BlackWallBanner => "block.minecraft.black_wall_banner",
+            BlockKind::RedSandstone => "block.minecraft.red_sandstone",
+            BlockKind::ChiseledRedSandstone => "block.minecraft.chiseled_red_sandstone",
+            BlockKind::CutRedSandstone => "block.minecraft.cut_red_sandstone",
+            BlockKind::RedSandstoneStairs => "block.minecraft.red_sandstone_stairs",
+            BlockKind::OakSlab => "block.minecraft.oak_slab",
+            BlockKind::SpruceSlab => "block.minecraft.spruce_slab",
+            BlockKind::BirchSlab => "block.minecraft.birch_slab",
+            BlockKind::JungleSlab => "block.minecraft.jungle_slab",
+            BlockKind::AcaciaSlab => "block.minecraft.acacia_slab",
+            BlockKind::DarkOakSlab => "block.minecraft.dark_oak_slab",
+            BlockKind::StoneSlab => "block.minecraft.stone_slab",
+            BlockKind::SandstoneSlab => "block.minecraft.sandstone_slab",
+            BlockKind::PetrifiedOakSlab => "block.minecraft.petrified_oak_slab",
+            BlockKind::CobblestoneSlab => "block.minecraft.cobblestone_slab",
+            BlockKind::BrickSlab => "block.minecraft.brick_slab",
+            BlockKind::StoneBrickSlab => "block.minecraft.stone_brick_slab",
+            BlockKind::NetherBrickSlab => "block.minecraft.nether_brick_slab",
+            BlockKind::QuartzSlab => "block.minecraft.quartz_slab",
+            BlockKind::RedSandstoneSlab => "block.minecraft.red_sandstone_slab",
+            BlockKind::PurpurSlab => "block.minecraft.purpur_slab",
+            BlockKind::SmoothStone => "block.minecraft.smooth_stone",
+            BlockKind::SmoothSandstone => "block.minecraft.smooth_sandstone",
+            BlockKind::SmoothQuartz => "block.minecraft.smooth_quartz",
+            BlockKind::SmoothRedSandstone => "block.minecraft.smooth_red_sandstone",
+            BlockKind::SpruceFenceGate => "block.minecraft.spruce_fence_gate",
+            BlockKind::BirchFenceGate => "block.minecraft.birch_fence_gate",
+            BlockKind::JungleFenceGate => "block.minecraft.jungle_fence_gate",
+            BlockKind::AcaciaFenceGate => "block.minecraft.acacia_fence_gate",
+            BlockKind::DarkOakFenceGate => "block.minecraft.dark_oak_fence_gate",
+            BlockKind::SpruceFence => "block.minecraft.spruce_fence",
+            BlockKind::BirchFence => "block.minecraft.birch_fence",
+            BlockKind::JungleFence => "block.minecraft.jungle_fence",
+            BlockKind::AcaciaFence => "block.minecraft.acacia_fence",
+            BlockKind::DarkOakFence => "block.minecraft.dark_oak_fence",
+            BlockKind::SpruceDoor => "block.minecraft.spruce_door",
+            BlockKind::BirchDoor => "block.minecraft.birch_door",
+            BlockKind::JungleDoor => "block.minecraft.jungle_door",
+            BlockKind::AcaciaDoor => "block.minecraft.acacia_door",
+            BlockKind::DarkOakDoor => "block.minecraft.dark_oak_door",
+            BlockKind::EndRod => "block.minecraft.end_rod",
+            BlockKind::ChorusPlant => "block.minecraft.chorus_plant",
+            BlockKind::ChorusFlower => "block.minecraft.chorus_flower",
+            BlockKind::PurpurBlock => "block.minecraft.purpur_block",
+            BlockKind::PurpurPillar => "block.minecraft.purpur_pillar",
+            BlockKind::PurpurStairs => "block.minecraft.purpur_stairs",
+            BlockKind::EndStoneBricks => "block.minecraft.end_stone_bricks",
+            BlockKind::Beetroots => "block.minecraft.beetroots",
+            BlockKind::GrassPath => "block.minecraft.grass_path",
+            BlockKind::EndGateway => "block.minecraft.end_gateway",
+            BlockKind::RepeatingCommandBlock => "block.minecraft.repeating_command_block",
+            BlockKind::ChainCommandBlock => "block.minecraft.chain_command_block",
+            BlockKind::FrostedIce => "block.minecraft.frosted_ice",
+            BlockKind::MagmaBlock => "block.minecraft.magma_block",
+            BlockKind::NetherWartBlock => "block.minecraft.nether_wart_block",
+            BlockKind::RedNetherBricks => "block.minecraft.red_nether_bricks",
+            BlockKind::BoneBlock => "block.minecraft.bone_block",
+            BlockKind::StructureVoid => "block.minecraft.structure_void",
+            BlockKind::Observer => "block.minecraft.observer",
+            BlockKind::ShulkerBox => "block.minecraft.shulker_box",
+            BlockKind::WhiteShulkerBox => "block.minecraft.white_shulker_box",
+            BlockKind::OrangeShulkerBox => "block.minecraft.orange_shulker_box",
+            BlockKind::MagentaShulkerBox => "block.minecraft.magenta_shulker_box",
+            BlockKind::LightBlueShulkerBox => "block.minecraft.light_blue_shulker_box",
+            BlockKind::YellowShulkerBox => "block.minecraft.yellow_shulker_box",
+            BlockKind::LimeShulkerBox => "block.minecraft.lime_shulker_box",
+            BlockKind::PinkShulkerBox => "block.minecraft.pink_shulker_box",
+            BlockKind::GrayShulkerBox => "block.minecraft.gray_shulker_box",
+            BlockKind::LightGrayShulkerBox => "block.minecraft.light_gray_shulker_box",
+            BlockKind::CyanShulkerBox => "block.minecraft.cyan_shulker_box",
+            BlockKind::PurpleShulkerBox => "block.minecraft.purple_shulker_box",
+            BlockKind::BlueShulkerBox => "block.minecraft.blue_shulker_box",
+            BlockKind::BrownShulkerBox => "block.minecraft.brown_shulker_box",
+            BlockKind::GreenShulkerBox => "block.minecraft.green_shulker_box",
+            BlockKind::RedShulkerBox => "block.minecraft.red_shulker_box",
+            BlockKind::BlackShulkerBox => "block.minecraft.black_shulker_box",
+            BlockKind::WhiteGlazedTerracotta => "block.minecraft.white_glazed_terracotta",
+            BlockKind::OrangeGlazedTerracotta => "block.minecraft.orange_glazed_terracotta",
+            BlockKind::MagentaGlazedTerracotta => "block.minecraft.magenta_glazed_terracotta",
+            BlockKind::LightBlueGlazedTerracotta => "block.minecraft.light_blue_glazed_terracotta",
+            BlockKind::YellowGlazedTerracotta => "block.minecraft.yellow_glazed_terracotta",
+            BlockKind::LimeGlazedTerracotta => "block.minecraft.lime_glazed_terracotta",
+            BlockKind::PinkGlazedTerracotta => "block.minecraft.pink_glazed_terracotta",
+            BlockKind::GrayGlazedTerracotta => "block.minecraft.gray_glazed_terracotta",
+            BlockKind::LightGrayGlazedTerracotta => "block.minecraft.light_gray_glazed_terracotta",
+            BlockKind::CyanGlazedTerracotta => "block.minecraft.cyan_glazed_terracotta",
+            BlockKind::PurpleGlazedTerracotta => "block.minecraft.purple_glazed_terracotta",
+            BlockKind::BlueGlazedTerracotta => "block.minecraft.blue_glazed_terracotta",
+            BlockKind::BrownGlazedTerracotta => "block.minecraft.brown_glazed_terracotta",
+            BlockKind::GreenGlazedTerracotta => "block.minecraft.green_glazed_terracotta",
+            BlockKind::RedGlazedTerracotta => "block.minecraft.red_glazed_terracotta",
+            BlockKind::BlackGlazedTerracotta => "block.minecraft.black_glazed_terracotta",
+            BlockKind::WhiteConcrete => "block.minecraft.white_concrete",
+            BlockKind::OrangeConcrete => "block.minecraft.orange_concrete",
+            BlockKind::MagentaConcrete => "block.minecraft.magenta_concrete",
+            BlockKind::LightBlueConcrete => "block.minecraft.light_blue_concrete",
+            BlockKind::YellowConcrete => "block.minecraft.yellow_concrete",
+            BlockKind::LimeConcrete => "block.minecraft.lime_concrete",
+            BlockKind::PinkConcrete => "block.minecraft.pink_concrete",
+            BlockKind::GrayConcrete => "block.minecraft.gray_concrete",
+            BlockKind::LightGrayConcrete => "block.minecraft.light_gray_concrete",
+            BlockKind::CyanConcrete => "block.minecraft.cyan_concrete",
+            BlockKind::PurpleConcrete => "block.minecraft.purple_concrete",
+            BlockKind::BlueConcrete => "block.minecraft.blue_concrete",
+            BlockKind::BrownConcrete => "block.minecraft.brown_concrete",
+            BlockKind::GreenConcrete => "block.minecraft.green_concrete",
+            BlockKind::RedConcrete => "block.minecraft.red_concrete",
+            BlockKind::BlackConcrete => "block.minecraft.black_concrete",
+            BlockKind::WhiteConcretePowder => "block.minecraft.white_concrete_powder",
+            BlockKind::OrangeConcretePowder => "block.minecraft.orange_concrete_powder",
+            BlockKind::MagentaConcretePowder => "block.minecraft.magenta_concrete_powder",
+            BlockKind::LightBlueConcretePowder => "block.minecraft.light_blue_concrete_powder",
+            BlockKind::YellowConcretePowder => "block.minecraft.yellow_concrete_powder",
+            BlockKind::LimeConcretePowder => "block.minecraft.lime_concrete_powder",
+            BlockKind::PinkConcretePowder => "block.minecraft.pink_concrete_powder",
+            BlockKind::GrayConcretePowder => "block.minecraft.gray_concrete_powder",
+            BlockKind::LightGrayConcretePowder => "block.minecraft.light_gray_concrete_powder",
+            BlockKind::CyanConcretePowder => "block.minecraft.cyan_concrete_powder",
+            BlockKind::PurpleConcretePowder => "block.minecraft.purple_concrete_powder",
+            BlockKind::BlueConcretePowder => "block.minecraft.blue_concrete_powder",
+            BlockKind::BrownConcretePowder => "block.minecraft.brown_concrete_powder",
+            BlockKind::GreenConcretePowder => "block.minecraft.green_concrete_powder",
+            BlockKind::RedConcretePowder => "block.minecraft.red_concrete_powder",
+            BlockKind::BlackConcretePowder => "block.minecraft.black_concrete_powder",
+            BlockKind::Kelp => "block.minecraft.kelp",
+            BlockKind::KelpPlant => "block.minecraft.kelp_plant",
+            BlockKind::DriedKelpBlock => "block.minecraft.dried_kelp_block",
+            BlockKind::TurtleEgg => "block.minecraft.turtle_egg",
+            BlockKind::DeadTubeCoralBlock => "block.minecraft.dead_tube_coral_block",
+            BlockKind::DeadBrainCoralBlock => "block.minecraft.dead_brain_coral_block",
+            BlockKind::DeadBubbleCoralBlock => "block.minecraft.dead_bubble_coral_block",
+            BlockKind::DeadFireCoralBlock => "block.minecraft.dead_fire_coral_block",
+            BlockKind::DeadHornCoralBlock => "block.minecraft.dead_horn_coral_block",
+            BlockKind::TubeCoralBlock => "block.minecraft.tube_coral_block",
+            BlockKind::BrainCoralBlock => "block.minecraft.brain_coral_block",
+            BlockKind::BubbleCoralBlock => "block.minecraft.bubble_coral_block",
+            BlockKind::FireCoralBlock => "block.minecraft.fire_coral_block",
+            BlockKind::HornCoralBlock => "block.minecraft.horn_coral_block",
+            BlockKind::DeadTubeCoral => "block.minecraft.dead_tube_coral",
+            BlockKind::DeadBrainCoral => "block.minecraft.dead_brain_coral",
+            BlockKind::DeadBubbleCoral => "block.minecraft.dead_bubble_coral",
+            BlockKind::DeadFireCoral => "block.minecraft.dead_fire_coral",
+            BlockKind::DeadHornCoral => "block.minecraft.dead_horn_coral",
+            BlockKind::TubeCoral => "block.minecraft.tube_coral",
+            BlockKind::BrainCoral => "block.minecraft.brain_coral",
+            BlockKind::BubbleCoral => "block.minecraft.bubble_coral",
+            BlockKind::FireCoral => "block.minecraft.fire_coral",
+            BlockKind::HornCoral => "block.minecraft.horn_coral",
+            BlockKind::DeadTubeCoralWallFan => "block.minecraft.dead_tube_coral_wall_fan",
+            BlockKind::DeadBrainCoralWallFan => "block.minecraft.dead_brain_coral_wall_fan",
+            BlockKind::DeadBubbleCoralWallFan => "block.minecraft.dead_bubble_coral_wall_fan",
+            BlockKind::DeadFireCoralWallFan => "block.minecraft.dead_fire_coral_wall_fan",
+            BlockKind::DeadHornCoralWallFan => "block.minecraft.dead_horn_coral_wall_fan",
+            BlockKind::TubeCoralWallFan => "block.minecraft.tube_coral_wall_fan",
+            BlockKind::BrainCoralWallFan => "block.minecraft.brain_coral_wall_fan",
+            BlockKind::BubbleCoralWallFan => "block.minecraft.bubble_coral_wall_fan",
+            BlockKind::FireCoralWallFan => "block.minecraft.fire_coral_wall_fan",
+            BlockKind::HornCoralWallFan => "block.minecraft.horn_coral_wall_fan",
+            BlockKind::DeadTubeCoralFan => "block.minecraft.dead_tube_coral_fan",
+            BlockKind::DeadBrainCoralFan => "block.minecraft.dead_brain_coral_fan",
+            BlockKind::DeadBubbleCoralFan => "block.minecraft.dead_bubble_coral_fan",
+            BlockKind::DeadFireCoralFan => "block.minecraft.dead_fire_coral_fan",
+            BlockKind::DeadHornCoralFan => "block.minecraft.dead_horn_coral_fan",
+            BlockKind::TubeCoralFan => "block.minecraft.tube_coral_fan",
+            BlockKind::BrainCoralFan => "block.minecraft.brain_coral_fan",
+            BlockKind::BubbleCoralFan => "block.minecraft.bubble_coral_fan",
+            BlockKind::FireCoralFan => "block.minecraft.fire_coral_fan",
+            BlockKind::HornCoralFan => "block.minecraft.horn_coral_fan",
+            BlockKind::SeaPickle => "block.minecraft.sea_pickle",
+            BlockKind::BlueIce => "block.minecraft.blue_ice",
+            BlockKind::Conduit => "block.minecraft.conduit",
+            BlockKind::VoidAir => "block.minecraft.void_air",
+            BlockKind::CaveAir => "block.minecraft.cave_air",
+            BlockKind::BubbleColumn => "block.minecraft.bubble_column",
+            BlockKind::StructureBlock => "block.minecraft.structure_block",
+        }
+    }
+}
+#[doc = "Invokes the given closure once for every `BlockKind`, in ID order."]
+#[doc = ""]
+#[doc = "Since this expands to every variant, code built on it"]
+#[doc = "automatically covers newly added blocks."]
+#[macro_export]
+macro_rules! for_each_block_kind {
+    ($f:expr) => {{
+        let mut f = $f;
+        f($crate::BlockKind::Air);
+        f($crate::BlockKind::Stone);
+        f($crate::BlockKind::Granite);
+        f($crate::BlockKind::PolishedGranite);
+        f($crate::BlockKind::Diorite);
+        f($crate::BlockKind::PolishedDiorite);
+        f($crate::BlockKind::Andesite);
+        f($crate::BlockKind::PolishedAndesite);
+        f($crate::BlockKind::GrassBlock);
+        f($crate::BlockKind::Dirt);
+        f($crate::BlockKind::CoarseDirt);
+        f($crate::BlockKind::Podzol);
+        f($crate::BlockKind::Cobblestone);
+        f($crate::BlockKind::OakPlanks);
+        f($crate::BlockKind::SprucePlanks);
+        f($crate::BlockKind::BirchPlanks);
+        f($crate::BlockKind::JunglePlanks);
+        f($crate::BlockKind::AcaciaPlanks);
+        f($crate::BlockKind::DarkOakPlanks);
+        f($crate::BlockKind::OakSapling);
+        f($crate::BlockKind::SpruceSapling);
+        f($crate::BlockKind::BirchSapling);
+        f($crate::BlockKind::JungleSapling);
+        f($crate::BlockKind::AcaciaSapling);
+        f($crate::BlockKind::DarkOakSapling);
+        f($crate::BlockKind::Bedrock);
+        f($crate::BlockKind::Water);
+        f($crate::BlockKind::Lava);
+        f($crate::BlockKind::Sand);
+        f($crate::BlockKind::RedSand);
+        f($crate::BlockKind::Gravel);
+        f($crate::BlockKind::GoldOre);
+        f($crate::BlockKind::IronOre);
+        f($crate::BlockKind::CoalOre);
+        f($crate::BlockKind::OakLog);
+        f($crate::BlockKind::SpruceLog);
+        f($crate::BlockKind::BirchLog);
+        f($crate::BlockKind::JungleLog);
+        f($crate::BlockKind::AcaciaLog);
+        f($crate::BlockKind::DarkOakLog);
+        f($crate::BlockKind::StrippedSpruceLog);
+        f($crate::BlockKind::StrippedBirchLog);
+        f($crate::BlockKind::StrippedJungleLog);
+        f($crate::BlockKind::StrippedAcaciaLog);
+        f($crate::BlockKind::StrippedDarkOakLog);
+        f($crate::BlockKind::StrippedOakLog);
+        f($crate::BlockKind::OakWood);
+        f($crate::BlockKind::SpruceWood);
+        f($crate::BlockKind::BirchWood);
+        f($crate::BlockKind::JungleWood);
+        f($crate::BlockKind::AcaciaWood);
+        f($crate::BlockKind::DarkOakWood);
+        f($crate::BlockKind::StrippedOakWood);
+        f($crate::BlockKind::StrippedSpruceWood);
+        f($crate::BlockKind::StrippedBirchWood);
+        f($crate::BlockKind::StrippedJungleWood);
+        f($crate::BlockKind::StrippedAcaciaWood);
+        f($crate::BlockKind::StrippedDarkOakWood);
+        f($crate::BlockKind::OakLeaves);
+        f($crate::BlockKind::SpruceLeaves);
+        f($crate::BlockKind::BirchLeaves);
+        f($crate::BlockKind::JungleLeaves);
+        f($crate::BlockKind::AcaciaLeaves);
+        f($crate::BlockKind::DarkOakLeaves);
+        f($crate::BlockKind::Sponge);
+        f($crate::BlockKind::WetSponge);
+        f($crate::BlockKind::Glass);
+        f($crate::BlockKind::LapisOre);
+        f($crate::BlockKind::LapisBlock);
+        f($crate::BlockKind::Dispenser);
+        f($crate::BlockKind::Sandstone);
+        f($crate::BlockKind::ChiseledSandstone);
+        f($crate::BlockKind::CutSandstone);
+        f($crate::BlockKind::NoteBlock);
+        f($crate::BlockKind::WhiteBed);
+        f($crate::BlockKind::OrangeBed);
+        f($crate::BlockKind::MagentaBed);
+        f($crate::BlockKind::LightBlueBed);
+        f($crate::BlockKind::YellowBed);
+        f($crate::BlockKind::LimeBed);
+        f($crate::BlockKind::PinkBed);
+        f($crate::BlockKind::GrayBed);
+        f($crate::BlockKind::LightGrayBed);
+        f($crate::BlockKind::CyanBed);
+        f($crate::BlockKind::PurpleBed);
+        f($crate::BlockKind::BlueBed);
+        f($crate::BlockKind::BrownBed);
+        f($crate::BlockKind::GreenBed);
+        f($crate::BlockKind::RedBed);
+        f($crate::BlockKind::BlackBed);
+        f($crate::BlockKind::PoweredRail);
+        f($crate::BlockKind::DetectorRail);
+        f($crate::BlockKind::StickyPiston);
+        f($crate::BlockKind::Cobweb);
+        f($crate::BlockKind::Grass);
+        f($crate::BlockKind::Fern);
+        f($crate::BlockKind::DeadBush);
+        f($crate::BlockKind::Seagrass);
+        f($crate::BlockKind::TallSeagrass);
+        f($crate::BlockKind::Piston);
+        f($crate::BlockKind::PistonHead);
+        f($crate::BlockKind::WhiteWool);
+        f($crate::BlockKind::OrangeWool);
+        f($crate::BlockKind::MagentaWool);
+        f($crate::BlockKind::LightBlueWool);
+        f($crate::BlockKind::YellowWool);
+        f($crate::BlockKind::LimeWool);
+        f($crate::BlockKind::PinkWool);
+        f($crate::BlockKind::GrayWool);
+        f($crate::BlockKind::LightGrayWool);
+        f($crate::BlockKind::CyanWool);
+        f($crate::BlockKind::PurpleWool);
+        f($crate::BlockKind::BlueWool);
+        f($crate::BlockKind::BrownWool);
+        f($crate::BlockKind::GreenWool);
+        f($crate::BlockKind::RedWool);
+        f($crate::BlockKind::BlackWool);
+        f($crate::BlockKind::MovingPiston);
+        f($crate::BlockKind::Dandelion);
+        f($crate::BlockKind::Poppy);
+        f($crate::BlockKind::BlueOrchid);
+        f($crate::BlockKind::Allium);
+        f($crate::BlockKind::AzureBluet);
+        f($crate::BlockKind::RedTulip);
+        f($crate::BlockKind::OrangeTulip);
+        f($crate::BlockKind::WhiteTulip);
+        f($crate::BlockKind::PinkTulip);
+        f($crate::BlockKind::OxeyeDaisy);
+        f($crate::BlockKind::BrownMushroom);
+        f($crate::BlockKind::RedMushroom);
+        f($crate::BlockKind::GoldBlock);
+        f($crate::BlockKind::IronBlock);
+        f($crate::BlockKind::Bricks);
+        f($crate::BlockKind::Tnt);
+        f($crate::BlockKind::Bookshelf);
+        f($crate::BlockKind::MossyCobblestone);
+        f($crate::BlockKind::Obsidian);
+        f($crate::BlockKind::Torch);
+        f($crate::BlockKind::WallTorch);
+        f($crate::BlockKind::Fire);
+        f($crate::BlockKind::Spawner);
+        f($crate::BlockKind::OakStairs);
+        f($crate::BlockKind::Chest);
+        f($crate::BlockKind::RedstoneWire);
+        f($crate::BlockKind::DiamondOre);
+        f($crate::BlockKind::DiamondBlock);
+        f($crate::BlockKind::CraftingTable);
+        f($crate::BlockKind::Wheat);
+        f($crate::BlockKind::Farmland);
+        f($crate::BlockKind::Furnace);
+        f($crate::BlockKind::Sign);
+        f($crate::BlockKind::OakDoor);
+        f($crate::BlockKind::Ladder);
+        f($crate::BlockKind::Rail);
+        f($crate::BlockKind::CobblestoneStairs);
+        f($crate::BlockKind::WallSign);
+        f($crate::BlockKind::Lever);
+        f($crate::BlockKind::StonePressurePlate);
+        f($crate::BlockKind::IronDoor);
+        f($crate::BlockKind::OakPressurePlate);
+        f($crate::BlockKind::SprucePressurePlate);
+        f($crate::BlockKind::BirchPressurePlate);
+        f($crate::BlockKind::JunglePressurePlate);
+        f($crate::BlockKind::AcaciaPressurePlate);
+        f($crate::BlockKind::DarkOakPressurePlate);
+        f($crate::BlockKind::RedstoneOre);
+        f($crate::BlockKind::RedstoneTorch);
+        f($crate::BlockKind::RedstoneWallTorch);
+        f($crate::BlockKind::StoneButton);
+        f($crate::BlockKind::Snow);
+        f($crate::BlockKind::Ice);
+        f($crate::BlockKind::SnowBlock);
+        f($crate::BlockKind::Cactus);
+        f($crate::BlockKind::Clay);
+        f($crate::BlockKind::SugarCane);
+        f($crate::BlockKind::Jukebox);
+        f($crate::BlockKind::OakFence);
+        f($crate::BlockKind::Pumpkin);
+        f($crate::BlockKind::Netherrack);
+        f($crate::BlockKind::SoulSand);
+        f($crate::BlockKind::Glowstone);
+        f($crate::BlockKind::NetherPortal);
+        f($crate::BlockKind::CarvedPumpkin);
+        f($crate::BlockKind::JackOLantern);
+        f($crate::BlockKind::Cake);
+        f($crate::BlockKind::Repeater);
+        f($crate::BlockKind::WhiteStainedGlass);
+        f($crate::BlockKind::OrangeStainedGlass);
+        f($crate::BlockKind::MagentaStainedGlass);
+        f($crate::BlockKind::LightBlueStainedGlass);
+        f($crate::BlockKind::YellowStainedGlass);
+        f($crate::BlockKind::LimeStainedGlass);
+        f($crate::BlockKind::PinkStainedGlass);
+        f($crate::BlockKind::GrayStainedGlass);
+        f($crate::BlockKind::LightGrayStainedGlass);
+        f($crate::BlockKind::CyanStainedGlass);
+        f($crate::BlockKind::PurpleStainedGlass);
+        f($crate::BlockKind::BlueStainedGlass);
+        f($crate::BlockKind::BrownStainedGlass);
+        f($crate::BlockKind::GreenStainedGlass);
+        f($crate::BlockKind::RedStainedGlass);
+        f($crate::BlockKind::BlackStainedGlass);
+        f($crate::BlockKind::OakTrapdoor);
+        f($crate::BlockKind::SpruceTrapdoor);
+        f($crate::BlockKind::BirchTrapdoor);
+        f($crate::BlockKind::JungleTrapdoor);
+        f($crate::BlockKind::AcaciaTrapdoor);
+        f($crate::BlockKind::DarkOakTrapdoor);
+        f($crate::BlockKind::InfestedStone);
+        f($crate::BlockKind::InfestedCobblestone);
+        f($crate::BlockKind::InfestedStoneBricks);
+        f($crate::BlockKind::InfestedMossyStoneBricks);
+        f($crate::BlockKind::InfestedCrackedStoneBricks);
+        f($crate::BlockKind::InfestedChiseledStoneBricks);
+        f($crate::BlockKind::StoneBricks);
+        f($crate::BlockKind::MossyStoneBricks);
+        f($crate::BlockKind::CrackedStoneBricks);
+        f($crate::BlockKind::ChiseledStoneBricks);
+        f($crate::BlockKind::BrownMushroomBlock);
+        f($crate::BlockKind::RedMushroomBlock);
+        f($crate::BlockKind::MushroomStem);
+        f($crate::BlockKind::IronBars);
+        f($crate::BlockKind::GlassPane);
+        f($crate::BlockKind::Melon);
+        f($crate::BlockKind::AttachedPumpkinStem);
+        f($crate::BlockKind::AttachedMelonStem);
+        f($crate::BlockKind::PumpkinStem);
+        f($crate::BlockKind::MelonStem);
+        f($crate::BlockKind::Vine);
+        f($crate::BlockKind::OakFenceGate);
+        f($crate::BlockKind::BrickStairs);
+        f($crate::BlockKind::StoneBrickStairs);
+        f($crate::BlockKind::Mycelium);
+        f($crate::BlockKind::LilyPad);
+        f($crate::BlockKind::NetherBricks);
+        f($crate::BlockKind::NetherBrickFence);
+        f($crate::BlockKind::NetherBrickStairs);
+        f($crate::BlockKind::NetherWart);
+        f($crate::BlockKind::EnchantingTable);
+        f($crate::BlockKind::BrewingStand);
+        f($crate::BlockKind::Cauldron);
+        f($crate::BlockKind::EndPortal);
+        f($crate::BlockKind::EndPortalFrame);
+        f($crate::BlockKind::EndStone);
+        f($crate::BlockKind::DragonEgg);
+        f($crate::BlockKind::RedstoneLamp);
+        f($crate::BlockKind::Cocoa);
+        f($crate::BlockKind::SandstoneStairs);
+        f($crate::BlockKind::EmeraldOre);
+        f($crate::BlockKind::EnderChest);
+        f($crate::BlockKind::TripwireHook);
+        f($crate::BlockKind::Tripwire);
+        f($crate::BlockKind::EmeraldBlock);
+        f($crate::BlockKind::SpruceStairs);
+        f($crate::BlockKind::BirchStairs);
+        f($crate::BlockKind::JungleStairs);
+        f($crate::BlockKind::CommandBlock);
+        f($crate::BlockKind::Beacon);
+        f($crate::BlockKind::CobblestoneWall);
+        f($crate::BlockKind::MossyCobblestoneWall);
+        f($crate::BlockKind::FlowerPot);
+        f($crate::BlockKind::PottedOakSapling);
+        f($crate::BlockKind::PottedSpruceSapling);
+        f($crate::BlockKind::PottedBirchSapling);
+        f($crate::BlockKind::PottedJungleSapling);
+        f($crate::BlockKind::PottedAcaciaSapling);
+        f($crate::BlockKind::PottedDarkOakSapling);
+        f($crate::BlockKind::PottedFern);
+        f($crate::BlockKind::PottedDandelion);
+        f($crate::BlockKind::PottedPoppy);
+        f($crate::BlockKind::PottedBlueOrchid);
+        f($crate::BlockKind::PottedAllium);
+        f($crate::BlockKind::PottedAzureBluet);
+        f($crate::BlockKind::PottedRedTulip);
+        f($crate::BlockKind::PottedOrangeTulip);
+        f($crate::BlockKind::PottedWhiteTulip);
+        f($crate::BlockKind::PottedPinkTulip);
+        f($crate::BlockKind::PottedOxeyeDaisy);
+        f($crate::BlockKind::PottedRedMushroom);
+        f($crate::BlockKind::PottedBrownMushroom);
+        f($crate::BlockKind::PottedDeadBush);
+        f($crate::BlockKind::PottedCactus);
+        f($crate::BlockKind::Carrots);
+        f($crate::BlockKind::Potatoes);
+        f($crate::BlockKind::OakButton);
+        f($crate::BlockKind::SpruceButton);
+        f($crate::BlockKind::BirchButton);
+        f($crate::BlockKind::JungleButton);
+        f($crate::BlockKind::AcaciaButton);
+        f($crate::BlockKind::DarkOakButton);
+        f($crate::BlockKind::SkeletonWallSkull);
+        f($crate::BlockKind::SkeletonSkull);
+        f($crate::BlockKind::WitherSkeletonWallSkull);
+        f($crate::BlockKind::WitherSkeletonSkull);
+        f($crate::BlockKind::ZombieWallHead);
+        f($crate::BlockKind::ZombieHead);
+        f($crate::BlockKind::PlayerWallHead);
+        f($crate::BlockKind::PlayerHead);
+        f($crate::BlockKind::CreeperWallHead);
+        f($crate::BlockKind::CreeperHead);
+        f($crate::BlockKind::DragonWallHead);
+        f($crate::BlockKind::DragonHead);
+        f($crate::BlockKind::Anvil);
+        f($crate::BlockKind::ChippedAnvil);
+        f($crate::BlockKind::DamagedAnvil);
+        f($crate::BlockKind::TrappedChest);
+        f($crate::BlockKind::LightWeightedPressurePlate);
+        f($crate::BlockKind::HeavyWeightedPressurePlate);
+        f($crate::BlockKind::Comparator);
+        f($crate::BlockKind::DaylightDetector);
+        f($crate::BlockKind::RedstoneBlock);
+        f($crate::BlockKind::NetherQuartzOre);
+        f($crate::BlockKind::Hopper);
+        f($crate::BlockKind::QuartzBlock);
+        f($crate::BlockKind::ChiseledQuartzBlock);
+        f($crate::BlockKind::QuartzPillar);
+        f($crate::BlockKind::QuartzStairs);
+        f($crate::BlockKind::ActivatorRail);
+        f($crate::BlockKind::Dropper);
+        f($crate::BlockKind::WhiteTerracotta);
+        f($crate::BlockKind::OrangeTerracotta);
+        f($crate::BlockKind::MagentaTerracotta);
+        f($crate::BlockKind::LightBlueTerracotta);
+        f($crate::BlockKind::YellowTerracotta);
+        f($crate::BlockKind::LimeTerracotta);
+        f($crate::BlockKind::PinkTerracotta);
+        f($crate::BlockKind::GrayTerracotta);
+        f($crate::BlockKind::LightGrayTerracotta);
+        f($crate::BlockKind::CyanTerracotta);
+        f($crate::BlockKind::PurpleTerracotta);
+        f($crate::BlockKind::BlueTerracotta);
+        f($crate::BlockKind::BrownTerracotta);
+        f($crate::BlockKind::GreenTerracotta);
+        f($crate::BlockKind::RedTerracotta);
+        f($crate::BlockKind::BlackTerracotta);
+        f($crate::BlockKind::WhiteStainedGlassPane);
+        f($crate::BlockKind::OrangeStainedGlassPane);
+        f($crate::BlockKind::MagentaStainedGlassPane);
+        f($crate::BlockKind::LightBlueStainedGlassPane);
+        f($crate::BlockKind::YellowStainedGlassPane);
+        f($crate::BlockKind::LimeStainedGlassPane);
+        f($crate::BlockKind::PinkStainedGlassPane);
+        f($crate::BlockKind::GrayStainedGlassPane);
+        f($crate::BlockKind::LightGrayStainedGlassPane);
+        f($crate::BlockKind::CyanStainedGlassPane);
+        f($crate::BlockKind::PurpleStainedGlassPane);
+        f($crate::BlockKind::BlueStainedGlassPane);
+        f($crate::BlockKind::BrownStainedGlassPane);
+        f($crate::BlockKind::GreenStainedGlassPane);
+        f($crate::BlockKind::RedStainedGlassPane);
+        f($crate::BlockKind::BlackStainedGlassPane);
+        f($crate::BlockKind::AcaciaStairs);
+        f($crate::BlockKind::DarkOakStairs);
+        f($crate::BlockKind::SlimeBlock);
+        f($crate::BlockKind::Barrier);
+        f($crate::BlockKind::IronTrapdoor);
+        f($crate::BlockKind::Prismarine);
+        f($crate::BlockKind::PrismarineBricks);
+        f($crate::BlockKind::DarkPrismarine);
+        f($crate::BlockKind::PrismarineStairs);
+        f($crate::BlockKind::PrismarineBrickStairs);
+        f($crate::BlockKind::DarkPrismarineStairs);
+        f($crate::BlockKind::PrismarineSlab);
+        f($crate::BlockKind::PrismarineBrickSlab);
+        f($crate::BlockKind::DarkPrismarineSlab);
+        f($crate::BlockKind::SeaLantern);
+        f($crate::BlockKind::HayBlock);
+        f($crate::BlockKind::WhiteCarpet);
+        f($crate::BlockKind::OrangeCarpet);
+        f($crate::BlockKind::MagentaCarpet);
+        f($crate::BlockKind::LightBlueCarpet);
+        f($crate::BlockKind::YellowCarpet);
+        f($crate::BlockKind::LimeCarpet);
+        f($crate::BlockKind::PinkCarpet);
+        f($crate::BlockKind::GrayCarpet);
+        f($crate::BlockKind::LightGrayCarpet);
+        f($crate::BlockKind::CyanCarpet);
+        f($crate::BlockKind::PurpleCarpet);
+        f($crate::BlockKind::BlueCarpet);
+        f($crate::BlockKind::BrownCarpet);
+        f($crate::BlockKind::GreenCarpet);
+        f($crate::BlockKind::RedCarpet);
+        f($crate::BlockKind::BlackCarpet);
+        f($crate::BlockKind::Terracotta);
+        f($crate::BlockKind::CoalBlock);
+        f($crate::BlockKind::PackedIce);
+        f($crate::BlockKind::Sunflower);
+        f($crate::BlockKind::Lilac);
+        f($crate::BlockKind::RoseBush);
+        f($crate::BlockKind::Peony);
+        f($crate::BlockKind::TallGrass);
+        f($crate::BlockKind::LargeFern);
+        f($crate::BlockKind::WhiteBanner);
+        f($crate::BlockKind::OrangeBanner);
+        f($crate::BlockKind::MagentaBanner);
+        f($crate::BlockKind::LightBlueBanner);
+        f($crate::BlockKind::YellowBanner);
+        f($crate::BlockKind::LimeBanner);
+        f($crate::BlockKind::PinkBanner);
+        f($crate::BlockKind::GrayBanner);
+        f($crate::BlockKind::LightGrayBanner);
+        f($crate::BlockKind::CyanBanner);
+        f($crate::BlockKind::PurpleBanner);
+        f($crate::BlockKind::BlueBanner);
+        f($crate::BlockKind::BrownBanner);
+        f($crate::BlockKind::GreenBanner);
+        f($crate::BlockKind::RedBanner);
+        f($crate::BlockKind::BlackBanner);
+        f($crate::BlockKind::WhiteWallBanner);
+        f($crate::BlockKind::OrangeWallBanner);
+        f($crate::BlockKind::MagentaWallBanner);
+        f($crate::BlockKind::LightBlueWallBanner);
+        f($crate::BlockKind::YellowWallBanner);
+        f($crate::BlockKind::LimeWallBanner);
+        f($crate::BlockKind::PinkWallBanner);
+        f($crate::BlockKind::GrayWallBanner);
+        f($crate::BlockKind::LightGrayWallBanner);
+        f($crate::BlockKind::CyanWallBanner);
+        f($crate::BlockKind::PurpleWallBanner);
+        f($crate::BlockKind::BlueWallBanner);
+        f($crate::BlockKind::BrownWallBanner);
+        f($crate::BlockKind::GreenWallBanner);
+        f($crate::BlockKind::RedWallBanner);
+        f($crate::BlockKind::BlackWallBanner);
+        f($crate::BlockKind::RedSandstone);
+        f($crate::BlockKind::ChiseledRedSandstone);
+        f($crate::BlockKind::CutRedSandstone);
+        f($crate::BlockKind::RedSandstoneStairs);
+        f($crate::BlockKind::OakSlab);
+        f($crate::BlockKind::SpruceSlab);
+        f($crate::BlockKind::BirchSlab);
+        f($crate::BlockKind::JungleSlab);
+        f($crate::BlockKind::AcaciaSlab);
+        f($crate::BlockKind::DarkOakSlab);
+        f($crate::BlockKind::StoneSlab);
+        f($crate::BlockKind::SandstoneSlab);
+        f($crate::BlockKind::PetrifiedOakSlab);
+        f($crate::BlockKind::CobblestoneSlab);
+        f($crate::BlockKind::BrickSlab);
+        f($crate::BlockKind::StoneBrickSlab);
+        f($crate::BlockKind::NetherBrickSlab);
+        f($crate::BlockKind::QuartzSlab);
+        f($crate::BlockKind::RedSandstoneSlab);
+        f($crate::BlockKind::PurpurSlab);
+        f($crate::BlockKind::SmoothStone);
+        f($crate::BlockKind::SmoothSandstone);
+        f($crate::BlockKind::SmoothQuartz);
+        f($crate::BlockKind::SmoothRedSandstone);
+        f($crate::BlockKind::SpruceFenceGate);
+        f($crate::BlockKind::BirchFenceGate);
+        f($crate::BlockKind::JungleFenceGate);
+        f($crate::BlockKind::AcaciaFenceGate);
+        f($crate::BlockKind::DarkOakFenceGate);
+        f($crate::BlockKind::SpruceFence);
+        f($crate::BlockKind::BirchFence);
+        f($crate::BlockKind::JungleFence);
+        f($crate::BlockKind::AcaciaFence);
+        f($crate::BlockKind::DarkOakFence);
+        f($crate::BlockKind::SpruceDoor);
+        f($crate::BlockKind::BirchDoor);
+        f($crate::BlockKind::JungleDoor);
+        f($crate::BlockKind::AcaciaDoor);
+        f($crate::BlockKind::DarkOakDoor);
+        f($crate::BlockKind::EndRod);
+        f($crate::BlockKind::ChorusPlant);
+        f($crate::BlockKind::ChorusFlower);
+        f($crate::BlockKind::PurpurBlock);
+        f($crate::BlockKind::PurpurPillar);
+        f($crate::BlockKind::PurpurStairs);
+        f($crate::BlockKind::EndStoneBricks);
+        f($crate::BlockKind::Beetroots);
+        f($crate::BlockKind::GrassPath);
+        f($crate::BlockKind::EndGateway);
+        f($crate::BlockKind::RepeatingCommandBlock);
+        f($crate::BlockKind::ChainCommandBlock);
+        f($crate::BlockKind::FrostedIce);
+        f($crate::BlockKind::MagmaBlock);
+        f($crate::BlockKind::NetherWartBlock);
+        f($crate::BlockKind::RedNetherBricks);
+        f($crate::BlockKind::BoneBlock);
+        f($crate::BlockKind::StructureVoid);
+        f($crate::BlockKind::Observer);
+        f($crate::BlockKind::ShulkerBox);
+        f($crate::BlockKind::WhiteShulkerBox);
+        f($crate::BlockKind::OrangeShulkerBox);
+        f($crate::BlockKind::MagentaShulkerBox);
+        f($crate::BlockKind::LightBlueShulkerBox);
+        f($crate::BlockKind::YellowShulkerBox);
+        f($crate::BlockKind::LimeShulkerBox);
+        f($crate::BlockKind::PinkShulkerBox);
+        f($crate::BlockKind::GrayShulkerBox);
+        f($crate::BlockKind::LightGrayShulkerBox);
+        f($crate::BlockKind::CyanShulkerBox);
+        f($crate::BlockKind::PurpleShulkerBox);
+        f($crate::BlockKind::BlueShulkerBox);
+        f($crate::BlockKind::BrownShulkerBox);
+        f($crate::BlockKind::GreenShulkerBox);
+        f($crate::BlockKind::RedShulkerBox);
+        f($crate::BlockKind::BlackShulkerBox);
+        f($crate::BlockKind::WhiteGlazedTerracotta);
+        f($crate::BlockKind::OrangeGlazedTerracotta);
+        f($crate::BlockKind::MagentaGlazedTerracotta);
+        f($crate::BlockKind::LightBlueGlazedTerracotta);
+        f($crate::BlockKind::YellowGlazedTerracotta);
+        f($crate::BlockKind::LimeGlazedTerracotta);
+        f($crate::BlockKind::PinkGlazedTerracotta);
+        f($crate::BlockKind::GrayGlazedTerracotta);
+        f($crate::BlockKind::LightGrayGlazedTerracotta);
+        f($crate::BlockKind::CyanGlazedTerracotta);
+        f($crate::BlockKind::PurpleGlazedTerracotta);
+        f($crate::BlockKind::BlueGlazedTerracotta);
+        f($crate::BlockKind::BrownGlazedTerracotta);
+        f($crate::BlockKind::GreenGlazedTerracotta);
+        f($crate::BlockKind::RedGlazedTerracotta);
+        f($crate::BlockKind::BlackGlazedTerracotta);
+        f($crate::BlockKind::WhiteConcrete);
+        f($crate::BlockKind::OrangeConcrete);
+        f($crate::BlockKind::MagentaConcrete);
+        f($crate::BlockKind::LightBlueConcrete);
+        f($crate::BlockKind::YellowConcrete);
+        f($crate::BlockKind::LimeConcrete);
+        f($crate::BlockKind::PinkConcrete);
+        f($crate::BlockKind::GrayConcrete);
+        f($crate::BlockKind::LightGrayConcrete);
+        f($crate::BlockKind::CyanConcrete);
+        f($crate::BlockKind::PurpleConcrete);
+        f($crate::BlockKind::BlueConcrete);
+        f($crate::BlockKind::BrownConcrete);
+        f($crate::BlockKind::GreenConcrete);
+        f($crate::BlockKind::RedConcrete);
+        f($crate::BlockKind::BlackConcrete);
+        f($crate::BlockKind::WhiteConcretePowder);
+        f($crate::BlockKind::OrangeConcretePowder);
+        f($crate::BlockKind::MagentaConcretePowder);
+        f($crate::BlockKind::LightBlueConcretePowder);
+        f($crate::BlockKind::YellowConcretePowder);
+        f($crate::BlockKind::LimeConcretePowder);
+        f($crate::BlockKind::PinkConcretePowder);
+        f($crate::BlockKind::GrayConcretePowder);
+        f($crate::BlockKind::LightGrayConcretePowder);
+        f($crate::BlockKind::CyanConcretePowder);
+        f($crate::BlockKind::PurpleConcretePowder);
+        f($crate::BlockKind::BlueConcretePowder);
+        f($crate::BlockKind::BrownConcretePowder);
+        f($crate::BlockKind::GreenConcretePowder);
+        f($crate::BlockKind::RedConcretePowder);
+        f($crate::BlockKind::BlackConcretePowder);
+        f($crate::BlockKind::Kelp);
+        f($crate::BlockKind::KelpPlant);
+        f($crate::BlockKind::DriedKelpBlock);
+        f($crate::BlockKind::TurtleEgg);
+        f($crate::BlockKind::DeadTubeCoralBlock);
+        f($crate::BlockKind::DeadBrainCoralBlock);
+        f($crate::BlockKind::DeadBubbleCoralBlock);
+        f($crate::BlockKind::DeadFireCoralBlock);
+        f($crate::BlockKind::DeadHornCoralBlock);
+        f($crate::BlockKind::TubeCoralBlock);
+        f($crate::BlockKind::BrainCoralBlock);
+        f($crate::BlockKind::BubbleCoralBlock);
+        f($crate::BlockKind::FireCoralBlock);
+        f($crate::BlockKind::HornCoralBlock);
+        f($crate::BlockKind::DeadTubeCoral);
+        f($crate::BlockKind::DeadBrainCoral);
+        f($crate::BlockKind::DeadBubbleCoral);
+        f($crate::BlockKind::DeadFireCoral);
+        f($crate::BlockKind::DeadHornCoral);
+        f($crate::BlockKind::TubeCoral);
+        f($crate::BlockKind::BrainCoral);
+        f($crate::BlockKind::BubbleCoral);
+        f($crate::BlockKind::FireCoral);
+        f($crate::BlockKind::HornCoral);
+        f($crate::BlockKind::DeadTubeCoralWallFan);
+        f($crate::BlockKind::DeadBrainCoralWallFan);
+        f($crate::BlockKind::DeadBubbleCoralWallFan);
+        f($crate::BlockKind::DeadFireCoralWallFan);
+        f($crate::BlockKind::DeadHornCoralWallFan);
+        f($crate::BlockKind::TubeCoralWallFan);
+        f($crate::BlockKind::BrainCoralWallFan);
+        f($crate::BlockKind::BubbleCoralWallFan);
+        f($crate::BlockKind::FireCoralWallFan);
+        f($crate::BlockKind::HornCoralWallFan);
+        f($crate::BlockKind::DeadTubeCoralFan);
+        f($crate::BlockKind::DeadBrainCoralFan);
+        f($crate::BlockKind::DeadBubbleCoralFan);
+        f($crate::BlockKind::DeadFireCoralFan);
+        f($crate::BlockKind::DeadHornCoralFan);
+        f($crate::BlockKind::TubeCoralFan);
+        f($crate::BlockKind::BrainCoralFan);
+        f($crate::BlockKind::BubbleCoralFan);
+        f($crate::BlockKind::FireCoralFan);
+        f($crate::BlockKind::HornCoralFan);
+        f($crate::BlockKind::SeaPickle);
+        f($crate::BlockKind::BlueIce);
+        f($crate::BlockKind::Conduit);
+        f($crate::BlockKind::VoidAir);
+        f($crate::BlockKind::CaveAir);
+        f($crate::BlockKind::BubbleColumn);
+        f($crate::BlockKind::StructureBlock);
+    }};
+}
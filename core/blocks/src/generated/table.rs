@@ -80,6 +80,8 @@ pub struct BlockTable {
 }
 impl BlockTable {
     #[doc = "Retrieves the `age_0_15` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn age_0_15(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.age_0_15[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -89,6 +91,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_15` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_age_0_15(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.age_0_15[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -99,6 +103,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `age_0_2` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn age_0_2(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.age_0_2[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -108,6 +114,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_2` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_age_0_2(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.age_0_2[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -118,6 +126,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `age_0_25` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn age_0_25(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.age_0_25[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -127,6 +137,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_25` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_age_0_25(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.age_0_25[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -137,6 +149,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `age_0_3` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn age_0_3(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.age_0_3[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -146,6 +160,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_3` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_age_0_3(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.age_0_3[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -156,6 +172,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `age_0_5` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn age_0_5(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.age_0_5[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -165,6 +183,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_5` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_age_0_5(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.age_0_5[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -175,6 +195,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `age_0_7` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn age_0_7(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.age_0_7[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -184,6 +206,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_7` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_age_0_7(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.age_0_7[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -194,6 +218,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `attached` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn attached(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.attached[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -203,6 +229,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `attached` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_attached(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.attached[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -213,6 +241,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `axis_xyz` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn axis_xyz(&self, kind: BlockKind, state: u16) -> Option<AxisXyz> {
         let (offset_coefficient, stride) = self.axis_xyz[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -222,6 +252,8 @@ impl BlockTable {
         Some(AxisXyz::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `axis_xyz` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_axis_xyz(&self, kind: BlockKind, state: u16, value: AxisXyz) -> Option<u16> {
         let (offset_coefficient, stride) = self.axis_xyz[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -232,6 +264,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `axis_xz` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn axis_xz(&self, kind: BlockKind, state: u16) -> Option<AxisXz> {
         let (offset_coefficient, stride) = self.axis_xz[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -241,6 +275,8 @@ impl BlockTable {
         Some(AxisXz::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `axis_xz` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_axis_xz(&self, kind: BlockKind, state: u16, value: AxisXz) -> Option<u16> {
         let (offset_coefficient, stride) = self.axis_xz[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -251,6 +287,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `bites` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn bites(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.bites[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -260,6 +298,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `bites` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_bites(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.bites[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -270,6 +310,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `cauldron_level` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn cauldron_level(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.cauldron_level[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -279,6 +321,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `cauldron_level` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_cauldron_level(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.cauldron_level[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -289,6 +333,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `chest_kind` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn chest_kind(&self, kind: BlockKind, state: u16) -> Option<ChestKind> {
         let (offset_coefficient, stride) = self.chest_kind[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -298,6 +344,8 @@ impl BlockTable {
         Some(ChestKind::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `chest_kind` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_chest_kind(&self, kind: BlockKind, state: u16, value: ChestKind) -> Option<u16> {
         let (offset_coefficient, stride) = self.chest_kind[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -308,6 +356,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `comparator_mode` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn comparator_mode(&self, kind: BlockKind, state: u16) -> Option<ComparatorMode> {
         let (offset_coefficient, stride) = self.comparator_mode[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -317,6 +367,8 @@ impl BlockTable {
         Some(ComparatorMode::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `comparator_mode` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_comparator_mode(
         &self,
         kind: BlockKind,
@@ -332,6 +384,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `conditional` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn conditional(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.conditional[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -341,6 +395,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `conditional` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_conditional(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.conditional[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -351,6 +407,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `delay` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn delay(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.delay[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -360,6 +418,8 @@ impl BlockTable {
         Some({ x as i32 + 1i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `delay` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_delay(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.delay[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -370,6 +430,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `disarmed` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn disarmed(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.disarmed[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -379,6 +441,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `disarmed` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_disarmed(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.disarmed[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -389,6 +453,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `distance` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn distance(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.distance[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -398,6 +464,8 @@ impl BlockTable {
         Some({ x as i32 + 1i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `distance` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_distance(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.distance[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -408,6 +476,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `down` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn down(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.down[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -417,6 +487,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `down` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_down(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.down[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -427,6 +499,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `drag` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn drag(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.drag[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -436,6 +510,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `drag` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_drag(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.drag[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -446,6 +522,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `east_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn east_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.east_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -455,6 +533,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `east_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_east_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.east_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -465,6 +545,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `east_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn east_wire(&self, kind: BlockKind, state: u16) -> Option<EastWire> {
         let (offset_coefficient, stride) = self.east_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -474,6 +556,8 @@ impl BlockTable {
         Some(EastWire::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `east_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_east_wire(&self, kind: BlockKind, state: u16, value: EastWire) -> Option<u16> {
         let (offset_coefficient, stride) = self.east_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -484,6 +568,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `eggs` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn eggs(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.eggs[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -493,6 +579,8 @@ impl BlockTable {
         Some({ x as i32 + 1i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `eggs` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_eggs(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.eggs[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -503,6 +591,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `enabled` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn enabled(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.enabled[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -512,6 +602,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `enabled` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_enabled(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.enabled[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -522,6 +614,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `extended` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn extended(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.extended[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -531,6 +625,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `extended` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_extended(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.extended[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -541,6 +637,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `eye` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn eye(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.eye[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -550,6 +648,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `eye` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_eye(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.eye[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -560,6 +660,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `face` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn face(&self, kind: BlockKind, state: u16) -> Option<Face> {
         let (offset_coefficient, stride) = self.face[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -569,6 +671,8 @@ impl BlockTable {
         Some(Face::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `face` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_face(&self, kind: BlockKind, state: u16, value: Face) -> Option<u16> {
         let (offset_coefficient, stride) = self.face[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -579,6 +683,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `facing_cardinal` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn facing_cardinal(&self, kind: BlockKind, state: u16) -> Option<FacingCardinal> {
         let (offset_coefficient, stride) = self.facing_cardinal[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -588,6 +694,8 @@ impl BlockTable {
         Some(FacingCardinal::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `facing_cardinal` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_facing_cardinal(
         &self,
         kind: BlockKind,
@@ -603,6 +711,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `facing_cardinal_and_down` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn facing_cardinal_and_down(
         &self,
         kind: BlockKind,
@@ -616,6 +726,8 @@ impl BlockTable {
         Some(FacingCardinalAndDown::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `facing_cardinal_and_down` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_facing_cardinal_and_down(
         &self,
         kind: BlockKind,
@@ -631,6 +743,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `facing_cubic` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn facing_cubic(&self, kind: BlockKind, state: u16) -> Option<FacingCubic> {
         let (offset_coefficient, stride) = self.facing_cubic[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -640,6 +754,8 @@ impl BlockTable {
         Some(FacingCubic::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `facing_cubic` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_facing_cubic(&self, kind: BlockKind, state: u16, value: FacingCubic) -> Option<u16> {
         let (offset_coefficient, stride) = self.facing_cubic[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -650,6 +766,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `half_top_bottom` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn half_top_bottom(&self, kind: BlockKind, state: u16) -> Option<HalfTopBottom> {
         let (offset_coefficient, stride) = self.half_top_bottom[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -659,6 +777,8 @@ impl BlockTable {
         Some(HalfTopBottom::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `half_top_bottom` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_half_top_bottom(
         &self,
         kind: BlockKind,
@@ -674,6 +794,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `half_upper_lower` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn half_upper_lower(&self, kind: BlockKind, state: u16) -> Option<HalfUpperLower> {
         let (offset_coefficient, stride) = self.half_upper_lower[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -683,6 +805,8 @@ impl BlockTable {
         Some(HalfUpperLower::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `half_upper_lower` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_half_upper_lower(
         &self,
         kind: BlockKind,
@@ -698,6 +822,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `has_bottle_0` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn has_bottle_0(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.has_bottle_0[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -707,6 +833,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `has_bottle_0` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_has_bottle_0(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.has_bottle_0[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -717,6 +845,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `has_bottle_1` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn has_bottle_1(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.has_bottle_1[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -726,6 +856,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `has_bottle_1` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_has_bottle_1(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.has_bottle_1[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -736,6 +868,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `has_bottle_2` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn has_bottle_2(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.has_bottle_2[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -745,6 +879,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `has_bottle_2` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_has_bottle_2(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.has_bottle_2[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -755,6 +891,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `has_record` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn has_record(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.has_record[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -764,6 +902,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `has_record` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_has_record(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.has_record[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -774,6 +914,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `hatch` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn hatch(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.hatch[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -783,6 +925,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `hatch` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_hatch(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.hatch[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -793,6 +937,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `hinge` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn hinge(&self, kind: BlockKind, state: u16) -> Option<Hinge> {
         let (offset_coefficient, stride) = self.hinge[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -802,6 +948,8 @@ impl BlockTable {
         Some(Hinge::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `hinge` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_hinge(&self, kind: BlockKind, state: u16, value: Hinge) -> Option<u16> {
         let (offset_coefficient, stride) = self.hinge[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -812,6 +960,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `in_wall` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn in_wall(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.in_wall[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -821,6 +971,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `in_wall` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_in_wall(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.in_wall[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -831,6 +983,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `instrument` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn instrument(&self, kind: BlockKind, state: u16) -> Option<Instrument> {
         let (offset_coefficient, stride) = self.instrument[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -840,6 +994,8 @@ impl BlockTable {
         Some(Instrument::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `instrument` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_instrument(&self, kind: BlockKind, state: u16, value: Instrument) -> Option<u16> {
         let (offset_coefficient, stride) = self.instrument[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -850,6 +1006,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `inverted` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn inverted(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.inverted[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -859,6 +1017,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `inverted` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_inverted(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.inverted[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -869,6 +1029,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `layers` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn layers(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.layers[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -878,6 +1040,8 @@ impl BlockTable {
         Some({ x as i32 + 1i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `layers` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_layers(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.layers[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -888,6 +1052,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `lit` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn lit(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.lit[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -897,6 +1063,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `lit` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_lit(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.lit[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -907,6 +1075,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `locked` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn locked(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.locked[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -916,6 +1086,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `locked` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_locked(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.locked[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -926,6 +1098,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `moisture` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn moisture(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.moisture[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -935,6 +1109,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `moisture` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_moisture(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.moisture[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -945,6 +1121,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `north_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn north_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.north_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -954,6 +1132,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `north_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_north_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.north_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -964,6 +1144,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `north_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn north_wire(&self, kind: BlockKind, state: u16) -> Option<NorthWire> {
         let (offset_coefficient, stride) = self.north_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -973,6 +1155,8 @@ impl BlockTable {
         Some(NorthWire::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `north_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_north_wire(&self, kind: BlockKind, state: u16, value: NorthWire) -> Option<u16> {
         let (offset_coefficient, stride) = self.north_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -983,6 +1167,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `note` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn note(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.note[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -992,6 +1178,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `note` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_note(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.note[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1002,6 +1190,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `occupied` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn occupied(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.occupied[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1011,6 +1201,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `occupied` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_occupied(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.occupied[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1021,6 +1213,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `open` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn open(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.open[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1030,6 +1224,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `open` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_open(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.open[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1040,6 +1236,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `part` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn part(&self, kind: BlockKind, state: u16) -> Option<Part> {
         let (offset_coefficient, stride) = self.part[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1049,6 +1247,8 @@ impl BlockTable {
         Some(Part::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `part` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_part(&self, kind: BlockKind, state: u16, value: Part) -> Option<u16> {
         let (offset_coefficient, stride) = self.part[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1059,6 +1259,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `persistent` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn persistent(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.persistent[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1068,6 +1270,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `persistent` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_persistent(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.persistent[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1078,6 +1282,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `pickles` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn pickles(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.pickles[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1087,6 +1293,8 @@ impl BlockTable {
         Some({ x as i32 + 1i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `pickles` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_pickles(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.pickles[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1097,6 +1305,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `piston_kind` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn piston_kind(&self, kind: BlockKind, state: u16) -> Option<PistonKind> {
         let (offset_coefficient, stride) = self.piston_kind[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1106,6 +1316,8 @@ impl BlockTable {
         Some(PistonKind::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `piston_kind` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_piston_kind(&self, kind: BlockKind, state: u16, value: PistonKind) -> Option<u16> {
         let (offset_coefficient, stride) = self.piston_kind[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1116,6 +1328,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `power` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn power(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.power[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1125,6 +1339,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `power` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_power(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.power[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1135,6 +1351,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `powered` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn powered(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.powered[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1144,6 +1362,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `powered` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_powered(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.powered[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1154,6 +1374,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `powered_rail_shape` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn powered_rail_shape(&self, kind: BlockKind, state: u16) -> Option<PoweredRailShape> {
         let (offset_coefficient, stride) = self.powered_rail_shape[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1163,6 +1385,8 @@ impl BlockTable {
         Some(PoweredRailShape::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `powered_rail_shape` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_powered_rail_shape(
         &self,
         kind: BlockKind,
@@ -1178,6 +1402,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `rail_shape` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn rail_shape(&self, kind: BlockKind, state: u16) -> Option<RailShape> {
         let (offset_coefficient, stride) = self.rail_shape[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1187,6 +1413,8 @@ impl BlockTable {
         Some(RailShape::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `rail_shape` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_rail_shape(&self, kind: BlockKind, state: u16, value: RailShape) -> Option<u16> {
         let (offset_coefficient, stride) = self.rail_shape[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1197,6 +1425,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `rotation` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn rotation(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.rotation[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1206,6 +1436,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `rotation` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_rotation(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.rotation[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1216,6 +1448,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `short` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn short(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.short[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1225,6 +1459,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `short` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_short(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.short[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1235,6 +1471,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `slab_kind` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn slab_kind(&self, kind: BlockKind, state: u16) -> Option<SlabKind> {
         let (offset_coefficient, stride) = self.slab_kind[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1244,6 +1482,8 @@ impl BlockTable {
         Some(SlabKind::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `slab_kind` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_slab_kind(&self, kind: BlockKind, state: u16, value: SlabKind) -> Option<u16> {
         let (offset_coefficient, stride) = self.slab_kind[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1254,6 +1494,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `snowy` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn snowy(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.snowy[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1263,6 +1505,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `snowy` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_snowy(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.snowy[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1273,6 +1517,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `south_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn south_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.south_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1282,6 +1528,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `south_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_south_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.south_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1292,6 +1540,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `south_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn south_wire(&self, kind: BlockKind, state: u16) -> Option<SouthWire> {
         let (offset_coefficient, stride) = self.south_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1301,6 +1551,8 @@ impl BlockTable {
         Some(SouthWire::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `south_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_south_wire(&self, kind: BlockKind, state: u16, value: SouthWire) -> Option<u16> {
         let (offset_coefficient, stride) = self.south_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1311,6 +1563,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `stage` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn stage(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.stage[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1320,6 +1574,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `stage` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_stage(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.stage[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1330,6 +1586,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `stairs_shape` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn stairs_shape(&self, kind: BlockKind, state: u16) -> Option<StairsShape> {
         let (offset_coefficient, stride) = self.stairs_shape[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1339,6 +1597,8 @@ impl BlockTable {
         Some(StairsShape::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `stairs_shape` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_stairs_shape(&self, kind: BlockKind, state: u16, value: StairsShape) -> Option<u16> {
         let (offset_coefficient, stride) = self.stairs_shape[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1349,6 +1609,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `structure_block_mode` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn structure_block_mode(&self, kind: BlockKind, state: u16) -> Option<StructureBlockMode> {
         let (offset_coefficient, stride) = self.structure_block_mode[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1358,6 +1620,8 @@ impl BlockTable {
         Some(StructureBlockMode::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `structure_block_mode` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_structure_block_mode(
         &self,
         kind: BlockKind,
@@ -1373,6 +1637,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `triggered` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn triggered(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.triggered[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1382,6 +1648,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `triggered` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_triggered(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.triggered[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1392,6 +1660,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `unstable` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn unstable(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.unstable[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1401,6 +1671,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `unstable` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_unstable(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.unstable[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1411,6 +1683,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `up` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn up(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.up[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1420,6 +1694,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `up` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_up(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.up[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1430,6 +1706,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `water_level` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn water_level(&self, kind: BlockKind, state: u16) -> Option<i32> {
         let (offset_coefficient, stride) = self.water_level[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1439,6 +1717,8 @@ impl BlockTable {
         Some({ x as i32 + 0i32 })
     }
     #[doc = "Updates the state value for the given block kind such that its `water_level` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_water_level(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
         let (offset_coefficient, stride) = self.water_level[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1449,6 +1729,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `waterlogged` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn waterlogged(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.waterlogged[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1458,6 +1740,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `waterlogged` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_waterlogged(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.waterlogged[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1468,6 +1752,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `west_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn west_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
         let (offset_coefficient, stride) = self.west_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1477,6 +1763,8 @@ impl BlockTable {
         Some(if x == 0 { false } else { true })
     }
     #[doc = "Updates the state value for the given block kind such that its `west_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_west_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
         let (offset_coefficient, stride) = self.west_connected[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1487,6 +1775,8 @@ impl BlockTable {
         Some(new as u16)
     }
     #[doc = "Retrieves the `west_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
+    #[inline]
+    #[must_use]
     pub fn west_wire(&self, kind: BlockKind, state: u16) -> Option<WestWire> {
         let (offset_coefficient, stride) = self.west_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
@@ -1496,6 +1786,8 @@ impl BlockTable {
         Some(WestWire::try_from(x).expect("invalid block state"))
     }
     #[doc = "Updates the state value for the given block kind such that its `west_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
+    #[inline]
+    #[must_use]
     pub fn set_west_wire(&self, kind: BlockKind, state: u16, value: WestWire) -> Option<u16> {
         let (offset_coefficient, stride) = self.west_wire[kind as u16 as usize];
         if offset_coefficient == 0 {
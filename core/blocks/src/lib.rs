@@ -9,6 +9,9 @@ mod categories;
 #[allow(warnings)]
 #[allow(clippy::all)]
 mod generated;
+mod material;
+mod piston;
+mod sound;
 
 static BLOCK_TABLE: Lazy<BlockTable> = Lazy::new(|| {
     let bytes = include_bytes!("generated/table.dat");
@@ -56,6 +59,9 @@ use once_cell::sync::Lazy;
 
 pub use crate::generated::table::*;
 pub use crate::generated::BlockKind;
+pub use material::BlockMaterial;
+pub use piston::PistonBehavior;
+pub use sound::BlockSoundGroup;
 
 use std::collections::HashSet;
 
@@ -180,6 +186,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn facing_cardinal() {
+        for (facing, name) in &[
+            (FacingCardinal::North, "north"),
+            (FacingCardinal::South, "south"),
+            (FacingCardinal::West, "west"),
+            (FacingCardinal::East, "east"),
+        ] {
+            assert_eq!(facing.as_str(), *name);
+            assert_eq!(name.parse::<FacingCardinal>().unwrap(), *facing);
+        }
+
+        let block = BlockId::furnace().with_facing_cardinal(FacingCardinal::East);
+        assert_eq!(block.facing_cardinal(), Some(FacingCardinal::East));
+        assert_eq!(block.to_properties_map()["facing_cardinal"], "east");
+
+        assert_eq!(BlockId::stone().facing_cardinal(), None);
+    }
+
+    #[test]
+    fn for_each_block_kind() {
+        let mut count = 0;
+        let mut last = None;
+        crate::for_each_block_kind!(|kind: BlockKind| {
+            assert!(last < Some(kind));
+            last = Some(kind);
+            count += 1;
+        });
+
+        assert_eq!(count, BlockKind::COUNT);
+        assert_eq!(last, Some(BlockKind::StructureBlock));
+    }
+
+    #[test]
+    fn translation_key() {
+        assert_eq!(BlockKind::Stone.translation_key(), "block.minecraft.stone");
+        assert_eq!(
+            BlockKind::OakPlanks.translation_key(),
+            "block.minecraft.oak_planks"
+        );
+    }
+
+    #[test]
+    fn material() {
+        assert_eq!(BlockKind::OakPlanks.material(), BlockMaterial::Wood);
+        assert_eq!(BlockKind::SprucePlanks.material(), BlockMaterial::Wood);
+        assert_eq!(BlockKind::Stone.material(), BlockMaterial::Stone);
+        assert_eq!(BlockKind::GrassBlock.material(), BlockMaterial::Dirt);
+        assert_eq!(BlockKind::IronBlock.material(), BlockMaterial::Metal);
+        assert_eq!(BlockKind::RedStainedGlass.material(), BlockMaterial::Glass);
+        assert_eq!(BlockKind::BlueWool.material(), BlockMaterial::Wool);
+    }
+
+    #[test]
+    fn sound_group() {
+        assert_eq!(BlockKind::Stone.sound_group(), BlockSoundGroup::Stone);
+        assert_eq!(
+            BlockKind::Stone.sound_group(),
+            BlockKind::Cobblestone.sound_group()
+        );
+        assert_eq!(BlockKind::GrassBlock.sound_group(), BlockSoundGroup::Grass);
+        assert_eq!(BlockKind::Dirt.sound_group(), BlockSoundGroup::Gravel);
+        assert_eq!(BlockKind::OakPlanks.sound_group(), BlockSoundGroup::Wood);
+        assert_eq!(BlockKind::Anvil.sound_group(), BlockSoundGroup::Anvil);
+    }
+
+    #[test]
+    fn piston_behavior() {
+        assert_eq!(BlockKind::Obsidian.piston_behavior(), PistonBehavior::Block);
+        assert_eq!(BlockKind::Poppy.piston_behavior(), PistonBehavior::Destroy);
+        assert_eq!(BlockKind::Stone.piston_behavior(), PistonBehavior::Normal);
+        assert_eq!(
+            BlockKind::WhiteGlazedTerracotta.piston_behavior(),
+            PistonBehavior::PushOnly
+        );
+        assert_eq!(BlockKind::Air.piston_behavior(), PistonBehavior::Ignore);
+    }
+
+    #[test]
+    fn const_accessors() {
+        const KEY: &str = BlockKind::Stone.translation_key();
+        const MATERIAL: BlockMaterial = BlockKind::OakPlanks.material();
+        const SOUND_GROUP: BlockSoundGroup = BlockKind::Anvil.sound_group();
+        const PISTON_BEHAVIOR: PistonBehavior = BlockKind::Obsidian.piston_behavior();
+
+        assert_eq!(KEY, "block.minecraft.stone");
+        assert_eq!(MATERIAL, BlockMaterial::Wood);
+        assert_eq!(SOUND_GROUP, BlockSoundGroup::Anvil);
+        assert_eq!(PISTON_BEHAVIOR, PistonBehavior::Block);
+    }
+
     #[test]
     fn property_starting_at_1() {
         let block = BlockId::snow().with_layers(1);
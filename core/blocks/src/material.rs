@@ -0,0 +1,490 @@
+use crate::BlockKind;
+
+/// The material a block is made of.
+///
+/// Materials group block kinds which behave alike,
+/// e.g. for sounds and tool effectiveness.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockMaterial {
+    /// Air and other empty blocks.
+    Air,
+    /// Stone, ores, bricks, terracotta and other rock-like blocks.
+    Stone,
+    /// Planks, logs and other wooden blocks.
+    Wood,
+    /// Dirt, grass blocks, farmland and clay.
+    Dirt,
+    /// Sand, gravel and concrete powder.
+    Sand,
+    /// Blocks made of metal, such as iron blocks and anvils.
+    Metal,
+    /// Glass and other glass-like light sources.
+    Glass,
+    /// Wool, carpets and beds.
+    Wool,
+    /// Saplings, flowers, crops and other plants.
+    Plant,
+    /// Leaves.
+    Leaves,
+    /// Water.
+    Water,
+    /// Lava.
+    Lava,
+    /// Ice.
+    Ice,
+    /// Snow layers and snow blocks.
+    Snow,
+    /// Blocks which fit none of the other materials, e.g. redstone components.
+    Other,
+}
+
+impl BlockKind {
+    /// Returns the material of this block kind.
+    pub const fn material(self) -> BlockMaterial {
+        match self {
+            BlockKind::Air | BlockKind::VoidAir | BlockKind::CaveAir => BlockMaterial::Air,
+            BlockKind::OakPlanks
+            | BlockKind::SprucePlanks
+            | BlockKind::BirchPlanks
+            | BlockKind::JunglePlanks
+            | BlockKind::AcaciaPlanks
+            | BlockKind::DarkOakPlanks
+            | BlockKind::OakLog
+            | BlockKind::SpruceLog
+            | BlockKind::BirchLog
+            | BlockKind::JungleLog
+            | BlockKind::AcaciaLog
+            | BlockKind::DarkOakLog
+            | BlockKind::StrippedSpruceLog
+            | BlockKind::StrippedBirchLog
+            | BlockKind::StrippedJungleLog
+            | BlockKind::StrippedAcaciaLog
+            | BlockKind::StrippedDarkOakLog
+            | BlockKind::StrippedOakLog
+            | BlockKind::OakWood
+            | BlockKind::SpruceWood
+            | BlockKind::BirchWood
+            | BlockKind::JungleWood
+            | BlockKind::AcaciaWood
+            | BlockKind::DarkOakWood
+            | BlockKind::StrippedOakWood
+            | BlockKind::StrippedSpruceWood
+            | BlockKind::StrippedBirchWood
+            | BlockKind::StrippedJungleWood
+            | BlockKind::StrippedAcaciaWood
+            | BlockKind::StrippedDarkOakWood
+            | BlockKind::NoteBlock
+            | BlockKind::Bookshelf
+            | BlockKind::OakStairs
+            | BlockKind::Chest
+            | BlockKind::CraftingTable
+            | BlockKind::Sign
+            | BlockKind::OakDoor
+            | BlockKind::Ladder
+            | BlockKind::WallSign
+            | BlockKind::OakPressurePlate
+            | BlockKind::SprucePressurePlate
+            | BlockKind::BirchPressurePlate
+            | BlockKind::JunglePressurePlate
+            | BlockKind::AcaciaPressurePlate
+            | BlockKind::DarkOakPressurePlate
+            | BlockKind::Jukebox
+            | BlockKind::OakFence
+            | BlockKind::OakTrapdoor
+            | BlockKind::SpruceTrapdoor
+            | BlockKind::BirchTrapdoor
+            | BlockKind::JungleTrapdoor
+            | BlockKind::AcaciaTrapdoor
+            | BlockKind::DarkOakTrapdoor
+            | BlockKind::BrownMushroomBlock
+            | BlockKind::RedMushroomBlock
+            | BlockKind::MushroomStem
+            | BlockKind::OakFenceGate
+            | BlockKind::SpruceStairs
+            | BlockKind::BirchStairs
+            | BlockKind::JungleStairs
+            | BlockKind::OakButton
+            | BlockKind::SpruceButton
+            | BlockKind::BirchButton
+            | BlockKind::JungleButton
+            | BlockKind::AcaciaButton
+            | BlockKind::DarkOakButton
+            | BlockKind::TrappedChest
+            | BlockKind::DaylightDetector
+            | BlockKind::AcaciaStairs
+            | BlockKind::DarkOakStairs
+            | BlockKind::WhiteBanner
+            | BlockKind::OrangeBanner
+            | BlockKind::MagentaBanner
+            | BlockKind::LightBlueBanner
+            | BlockKind::YellowBanner
+            | BlockKind::LimeBanner
+            | BlockKind::PinkBanner
+            | BlockKind::GrayBanner
+            | BlockKind::LightGrayBanner
+            | BlockKind::CyanBanner
+            | BlockKind::PurpleBanner
+            | BlockKind::BlueBanner
+            | BlockKind::BrownBanner
+            | BlockKind::GreenBanner
+            | BlockKind::RedBanner
+            | BlockKind::BlackBanner
+            | BlockKind::WhiteWallBanner
+            | BlockKind::OrangeWallBanner
+            | BlockKind::MagentaWallBanner
+            | BlockKind::LightBlueWallBanner
+            | BlockKind::YellowWallBanner
+            | BlockKind::LimeWallBanner
+            | BlockKind::PinkWallBanner
+            | BlockKind::GrayWallBanner
+            | BlockKind::LightGrayWallBanner
+            | BlockKind::CyanWallBanner
+            | BlockKind::PurpleWallBanner
+            | BlockKind::BlueWallBanner
+            | BlockKind::BrownWallBanner
+            | BlockKind::GreenWallBanner
+            | BlockKind::RedWallBanner
+            | BlockKind::BlackWallBanner
+            | BlockKind::OakSlab
+            | BlockKind::SpruceSlab
+            | BlockKind::BirchSlab
+            | BlockKind::JungleSlab
+            | BlockKind::AcaciaSlab
+            | BlockKind::DarkOakSlab
+            | BlockKind::SpruceFenceGate
+            | BlockKind::BirchFenceGate
+            | BlockKind::JungleFenceGate
+            | BlockKind::AcaciaFenceGate
+            | BlockKind::DarkOakFenceGate
+            | BlockKind::SpruceFence
+            | BlockKind::BirchFence
+            | BlockKind::JungleFence
+            | BlockKind::AcaciaFence
+            | BlockKind::DarkOakFence
+            | BlockKind::SpruceDoor
+            | BlockKind::BirchDoor
+            | BlockKind::JungleDoor
+            | BlockKind::AcaciaDoor
+            | BlockKind::DarkOakDoor => BlockMaterial::Wood,
+            BlockKind::GrassBlock
+            | BlockKind::Dirt
+            | BlockKind::CoarseDirt
+            | BlockKind::Podzol
+            | BlockKind::Farmland
+            | BlockKind::Clay
+            | BlockKind::Mycelium
+            | BlockKind::GrassPath => BlockMaterial::Dirt,
+            BlockKind::Sand
+            | BlockKind::RedSand
+            | BlockKind::Gravel
+            | BlockKind::SoulSand
+            | BlockKind::WhiteConcretePowder
+            | BlockKind::OrangeConcretePowder
+            | BlockKind::MagentaConcretePowder
+            | BlockKind::LightBlueConcretePowder
+            | BlockKind::YellowConcretePowder
+            | BlockKind::LimeConcretePowder
+            | BlockKind::PinkConcretePowder
+            | BlockKind::GrayConcretePowder
+            | BlockKind::LightGrayConcretePowder
+            | BlockKind::CyanConcretePowder
+            | BlockKind::PurpleConcretePowder
+            | BlockKind::BlueConcretePowder
+            | BlockKind::BrownConcretePowder
+            | BlockKind::GreenConcretePowder
+            | BlockKind::RedConcretePowder
+            | BlockKind::BlackConcretePowder => BlockMaterial::Sand,
+            BlockKind::LapisBlock
+            | BlockKind::GoldBlock
+            | BlockKind::IronBlock
+            | BlockKind::DiamondBlock
+            | BlockKind::IronDoor
+            | BlockKind::IronBars
+            | BlockKind::BrewingStand
+            | BlockKind::Cauldron
+            | BlockKind::EmeraldBlock
+            | BlockKind::CommandBlock
+            | BlockKind::Anvil
+            | BlockKind::ChippedAnvil
+            | BlockKind::DamagedAnvil
+            | BlockKind::LightWeightedPressurePlate
+            | BlockKind::HeavyWeightedPressurePlate
+            | BlockKind::RedstoneBlock
+            | BlockKind::Hopper
+            | BlockKind::IronTrapdoor
+            | BlockKind::RepeatingCommandBlock
+            | BlockKind::ChainCommandBlock
+            | BlockKind::StructureBlock => BlockMaterial::Metal,
+            BlockKind::Glass
+            | BlockKind::Glowstone
+            | BlockKind::WhiteStainedGlass
+            | BlockKind::OrangeStainedGlass
+            | BlockKind::MagentaStainedGlass
+            | BlockKind::LightBlueStainedGlass
+            | BlockKind::YellowStainedGlass
+            | BlockKind::LimeStainedGlass
+            | BlockKind::PinkStainedGlass
+            | BlockKind::GrayStainedGlass
+            | BlockKind::LightGrayStainedGlass
+            | BlockKind::CyanStainedGlass
+            | BlockKind::PurpleStainedGlass
+            | BlockKind::BlueStainedGlass
+            | BlockKind::BrownStainedGlass
+            | BlockKind::GreenStainedGlass
+            | BlockKind::RedStainedGlass
+            | BlockKind::BlackStainedGlass
+            | BlockKind::GlassPane
+            | BlockKind::RedstoneLamp
+            | BlockKind::Beacon
+            | BlockKind::WhiteStainedGlassPane
+            | BlockKind::OrangeStainedGlassPane
+            | BlockKind::MagentaStainedGlassPane
+            | BlockKind::LightBlueStainedGlassPane
+            | BlockKind::YellowStainedGlassPane
+            | BlockKind::LimeStainedGlassPane
+            | BlockKind::PinkStainedGlassPane
+            | BlockKind::GrayStainedGlassPane
+            | BlockKind::LightGrayStainedGlassPane
+            | BlockKind::CyanStainedGlassPane
+            | BlockKind::PurpleStainedGlassPane
+            | BlockKind::BlueStainedGlassPane
+            | BlockKind::BrownStainedGlassPane
+            | BlockKind::GreenStainedGlassPane
+            | BlockKind::RedStainedGlassPane
+            | BlockKind::BlackStainedGlassPane
+            | BlockKind::SeaLantern => BlockMaterial::Glass,
+            BlockKind::WhiteBed
+            | BlockKind::OrangeBed
+            | BlockKind::MagentaBed
+            | BlockKind::LightBlueBed
+            | BlockKind::YellowBed
+            | BlockKind::LimeBed
+            | BlockKind::PinkBed
+            | BlockKind::GrayBed
+            | BlockKind::LightGrayBed
+            | BlockKind::CyanBed
+            | BlockKind::PurpleBed
+            | BlockKind::BlueBed
+            | BlockKind::BrownBed
+            | BlockKind::GreenBed
+            | BlockKind::RedBed
+            | BlockKind::BlackBed
+            | BlockKind::WhiteWool
+            | BlockKind::OrangeWool
+            | BlockKind::MagentaWool
+            | BlockKind::LightBlueWool
+            | BlockKind::YellowWool
+            | BlockKind::LimeWool
+            | BlockKind::PinkWool
+            | BlockKind::GrayWool
+            | BlockKind::LightGrayWool
+            | BlockKind::CyanWool
+            | BlockKind::PurpleWool
+            | BlockKind::BlueWool
+            | BlockKind::BrownWool
+            | BlockKind::GreenWool
+            | BlockKind::RedWool
+            | BlockKind::BlackWool
+            | BlockKind::WhiteCarpet
+            | BlockKind::OrangeCarpet
+            | BlockKind::MagentaCarpet
+            | BlockKind::LightBlueCarpet
+            | BlockKind::YellowCarpet
+            | BlockKind::LimeCarpet
+            | BlockKind::PinkCarpet
+            | BlockKind::GrayCarpet
+            | BlockKind::LightGrayCarpet
+            | BlockKind::CyanCarpet
+            | BlockKind::PurpleCarpet
+            | BlockKind::BlueCarpet
+            | BlockKind::BrownCarpet
+            | BlockKind::GreenCarpet
+            | BlockKind::RedCarpet
+            | BlockKind::BlackCarpet => BlockMaterial::Wool,
+            BlockKind::OakSapling
+            | BlockKind::SpruceSapling
+            | BlockKind::BirchSapling
+            | BlockKind::JungleSapling
+            | BlockKind::AcaciaSapling
+            | BlockKind::DarkOakSapling
+            | BlockKind::Grass
+            | BlockKind::Fern
+            | BlockKind::DeadBush
+            | BlockKind::Seagrass
+            | BlockKind::TallSeagrass
+            | BlockKind::Dandelion
+            | BlockKind::Poppy
+            | BlockKind::BlueOrchid
+            | BlockKind::Allium
+            | BlockKind::AzureBluet
+            | BlockKind::RedTulip
+            | BlockKind::OrangeTulip
+            | BlockKind::WhiteTulip
+            | BlockKind::PinkTulip
+            | BlockKind::OxeyeDaisy
+            | BlockKind::BrownMushroom
+            | BlockKind::RedMushroom
+            | BlockKind::Wheat
+            | BlockKind::Cactus
+            | BlockKind::SugarCane
+            | BlockKind::Pumpkin
+            | BlockKind::CarvedPumpkin
+            | BlockKind::JackOLantern
+            | BlockKind::Melon
+            | BlockKind::AttachedPumpkinStem
+            | BlockKind::AttachedMelonStem
+            | BlockKind::PumpkinStem
+            | BlockKind::MelonStem
+            | BlockKind::Vine
+            | BlockKind::LilyPad
+            | BlockKind::NetherWart
+            | BlockKind::Cocoa
+            | BlockKind::Carrots
+            | BlockKind::Potatoes
+            | BlockKind::HayBlock
+            | BlockKind::Sunflower
+            | BlockKind::Lilac
+            | BlockKind::RoseBush
+            | BlockKind::Peony
+            | BlockKind::TallGrass
+            | BlockKind::LargeFern
+            | BlockKind::ChorusPlant
+            | BlockKind::ChorusFlower
+            | BlockKind::Beetroots
+            | BlockKind::NetherWartBlock
+            | BlockKind::Kelp
+            | BlockKind::KelpPlant
+            | BlockKind::DriedKelpBlock
+            | BlockKind::DeadTubeCoral
+            | BlockKind::DeadBrainCoral
+            | BlockKind::DeadBubbleCoral
+            | BlockKind::DeadFireCoral
+            | BlockKind::DeadHornCoral
+            | BlockKind::TubeCoral
+            | BlockKind::BrainCoral
+            | BlockKind::BubbleCoral
+            | BlockKind::FireCoral
+            | BlockKind::HornCoral
+            | BlockKind::DeadTubeCoralWallFan
+            | BlockKind::DeadBrainCoralWallFan
+            | BlockKind::DeadBubbleCoralWallFan
+            | BlockKind::DeadFireCoralWallFan
+            | BlockKind::DeadHornCoralWallFan
+            | BlockKind::TubeCoralWallFan
+            | BlockKind::BrainCoralWallFan
+            | BlockKind::BubbleCoralWallFan
+            | BlockKind::FireCoralWallFan
+            | BlockKind::HornCoralWallFan
+            | BlockKind::DeadTubeCoralFan
+            | BlockKind::DeadBrainCoralFan
+            | BlockKind::DeadBubbleCoralFan
+            | BlockKind::DeadFireCoralFan
+            | BlockKind::DeadHornCoralFan
+            | BlockKind::TubeCoralFan
+            | BlockKind::BrainCoralFan
+            | BlockKind::BubbleCoralFan
+            | BlockKind::FireCoralFan
+            | BlockKind::HornCoralFan
+            | BlockKind::SeaPickle => BlockMaterial::Plant,
+            BlockKind::OakLeaves
+            | BlockKind::SpruceLeaves
+            | BlockKind::BirchLeaves
+            | BlockKind::JungleLeaves
+            | BlockKind::AcaciaLeaves
+            | BlockKind::DarkOakLeaves => BlockMaterial::Leaves,
+            BlockKind::Water | BlockKind::BubbleColumn => BlockMaterial::Water,
+            BlockKind::Lava => BlockMaterial::Lava,
+            BlockKind::Ice | BlockKind::PackedIce | BlockKind::FrostedIce | BlockKind::BlueIce => {
+                BlockMaterial::Ice
+            }
+            BlockKind::Snow | BlockKind::SnowBlock => BlockMaterial::Snow,
+            BlockKind::Sponge
+            | BlockKind::WetSponge
+            | BlockKind::PoweredRail
+            | BlockKind::DetectorRail
+            | BlockKind::StickyPiston
+            | BlockKind::Cobweb
+            | BlockKind::Piston
+            | BlockKind::PistonHead
+            | BlockKind::MovingPiston
+            | BlockKind::Tnt
+            | BlockKind::Torch
+            | BlockKind::WallTorch
+            | BlockKind::Fire
+            | BlockKind::RedstoneWire
+            | BlockKind::Rail
+            | BlockKind::Lever
+            | BlockKind::RedstoneTorch
+            | BlockKind::RedstoneWallTorch
+            | BlockKind::StoneButton
+            | BlockKind::NetherPortal
+            | BlockKind::Cake
+            | BlockKind::Repeater
+            | BlockKind::EndPortal
+            | BlockKind::DragonEgg
+            | BlockKind::TripwireHook
+            | BlockKind::Tripwire
+            | BlockKind::FlowerPot
+            | BlockKind::PottedOakSapling
+            | BlockKind::PottedSpruceSapling
+            | BlockKind::PottedBirchSapling
+            | BlockKind::PottedJungleSapling
+            | BlockKind::PottedAcaciaSapling
+            | BlockKind::PottedDarkOakSapling
+            | BlockKind::PottedFern
+            | BlockKind::PottedDandelion
+            | BlockKind::PottedPoppy
+            | BlockKind::PottedBlueOrchid
+            | BlockKind::PottedAllium
+            | BlockKind::PottedAzureBluet
+            | BlockKind::PottedRedTulip
+            | BlockKind::PottedOrangeTulip
+            | BlockKind::PottedWhiteTulip
+            | BlockKind::PottedPinkTulip
+            | BlockKind::PottedOxeyeDaisy
+            | BlockKind::PottedRedMushroom
+            | BlockKind::PottedBrownMushroom
+            | BlockKind::PottedDeadBush
+            | BlockKind::PottedCactus
+            | BlockKind::SkeletonWallSkull
+            | BlockKind::SkeletonSkull
+            | BlockKind::WitherSkeletonWallSkull
+            | BlockKind::WitherSkeletonSkull
+            | BlockKind::ZombieWallHead
+            | BlockKind::ZombieHead
+            | BlockKind::PlayerWallHead
+            | BlockKind::PlayerHead
+            | BlockKind::CreeperWallHead
+            | BlockKind::CreeperHead
+            | BlockKind::DragonWallHead
+            | BlockKind::DragonHead
+            | BlockKind::Comparator
+            | BlockKind::ActivatorRail
+            | BlockKind::SlimeBlock
+            | BlockKind::Barrier
+            | BlockKind::EndRod
+            | BlockKind::EndGateway
+            | BlockKind::StructureVoid
+            | BlockKind::ShulkerBox
+            | BlockKind::WhiteShulkerBox
+            | BlockKind::OrangeShulkerBox
+            | BlockKind::MagentaShulkerBox
+            | BlockKind::LightBlueShulkerBox
+            | BlockKind::YellowShulkerBox
+            | BlockKind::LimeShulkerBox
+            | BlockKind::PinkShulkerBox
+            | BlockKind::GrayShulkerBox
+            | BlockKind::LightGrayShulkerBox
+            | BlockKind::CyanShulkerBox
+            | BlockKind::PurpleShulkerBox
+            | BlockKind::BlueShulkerBox
+            | BlockKind::BrownShulkerBox
+            | BlockKind::GreenShulkerBox
+            | BlockKind::RedShulkerBox
+            | BlockKind::BlackShulkerBox
+            | BlockKind::TurtleEgg
+            | BlockKind::Conduit => BlockMaterial::Other,
+            _ => BlockMaterial::Stone,
+        }
+    }
+}
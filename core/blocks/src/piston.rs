@@ -0,0 +1,246 @@
+use crate::{BlockKind, BlockMaterial};
+
+/// How a block reacts when a piston tries to push it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PistonBehavior {
+    /// The block is pushed and pulled normally.
+    Normal,
+    /// The block is destroyed (and drops) when pushed.
+    Destroy,
+    /// The block cannot be moved and stops the piston from extending.
+    Block,
+    /// The block can be pushed but not pulled by a sticky piston.
+    PushOnly,
+    /// The block is not affected by pistons at all.
+    Ignore,
+}
+
+impl BlockKind {
+    /// Returns how this block kind reacts to pistons.
+    ///
+    /// Blocks with block entities, portals and unbreakable
+    /// blocks are immovable; most plants and attachable
+    /// blocks are destroyed. Other blocks use the behavior
+    /// of their material.
+    pub const fn piston_behavior(self) -> PistonBehavior {
+        match self {
+            BlockKind::Obsidian
+            | BlockKind::Bedrock
+            | BlockKind::Barrier
+            | BlockKind::PistonHead
+            | BlockKind::MovingPiston
+            | BlockKind::NetherPortal
+            | BlockKind::EndPortal
+            | BlockKind::EndPortalFrame
+            | BlockKind::EndGateway
+            | BlockKind::Spawner
+            | BlockKind::Chest
+            | BlockKind::TrappedChest
+            | BlockKind::EnderChest
+            | BlockKind::Furnace
+            | BlockKind::Dispenser
+            | BlockKind::Dropper
+            | BlockKind::Hopper
+            | BlockKind::Jukebox
+            | BlockKind::EnchantingTable
+            | BlockKind::BrewingStand
+            | BlockKind::Beacon
+            | BlockKind::Conduit
+            | BlockKind::DaylightDetector
+            | BlockKind::CommandBlock
+            | BlockKind::RepeatingCommandBlock
+            | BlockKind::ChainCommandBlock
+            | BlockKind::StructureBlock
+            | BlockKind::Anvil
+            | BlockKind::ChippedAnvil
+            | BlockKind::DamagedAnvil
+            | BlockKind::Sign
+            | BlockKind::WallSign
+            | BlockKind::WhiteBanner
+            | BlockKind::OrangeBanner
+            | BlockKind::MagentaBanner
+            | BlockKind::LightBlueBanner
+            | BlockKind::YellowBanner
+            | BlockKind::LimeBanner
+            | BlockKind::PinkBanner
+            | BlockKind::GrayBanner
+            | BlockKind::LightGrayBanner
+            | BlockKind::CyanBanner
+            | BlockKind::PurpleBanner
+            | BlockKind::BlueBanner
+            | BlockKind::BrownBanner
+            | BlockKind::GreenBanner
+            | BlockKind::RedBanner
+            | BlockKind::BlackBanner
+            | BlockKind::WhiteWallBanner
+            | BlockKind::OrangeWallBanner
+            | BlockKind::MagentaWallBanner
+            | BlockKind::LightBlueWallBanner
+            | BlockKind::YellowWallBanner
+            | BlockKind::LimeWallBanner
+            | BlockKind::PinkWallBanner
+            | BlockKind::GrayWallBanner
+            | BlockKind::LightGrayWallBanner
+            | BlockKind::CyanWallBanner
+            | BlockKind::PurpleWallBanner
+            | BlockKind::BlueWallBanner
+            | BlockKind::BrownWallBanner
+            | BlockKind::GreenWallBanner
+            | BlockKind::RedWallBanner
+            | BlockKind::BlackWallBanner => PistonBehavior::Block,
+            BlockKind::WhiteGlazedTerracotta
+            | BlockKind::OrangeGlazedTerracotta
+            | BlockKind::MagentaGlazedTerracotta
+            | BlockKind::LightBlueGlazedTerracotta
+            | BlockKind::YellowGlazedTerracotta
+            | BlockKind::LimeGlazedTerracotta
+            | BlockKind::PinkGlazedTerracotta
+            | BlockKind::GrayGlazedTerracotta
+            | BlockKind::LightGrayGlazedTerracotta
+            | BlockKind::CyanGlazedTerracotta
+            | BlockKind::PurpleGlazedTerracotta
+            | BlockKind::BlueGlazedTerracotta
+            | BlockKind::BrownGlazedTerracotta
+            | BlockKind::GreenGlazedTerracotta
+            | BlockKind::RedGlazedTerracotta
+            | BlockKind::BlackGlazedTerracotta => PistonBehavior::PushOnly,
+            BlockKind::WhiteBed
+            | BlockKind::OrangeBed
+            | BlockKind::MagentaBed
+            | BlockKind::LightBlueBed
+            | BlockKind::YellowBed
+            | BlockKind::LimeBed
+            | BlockKind::PinkBed
+            | BlockKind::GrayBed
+            | BlockKind::LightGrayBed
+            | BlockKind::CyanBed
+            | BlockKind::PurpleBed
+            | BlockKind::BlueBed
+            | BlockKind::BrownBed
+            | BlockKind::GreenBed
+            | BlockKind::RedBed
+            | BlockKind::BlackBed
+            | BlockKind::WhiteCarpet
+            | BlockKind::OrangeCarpet
+            | BlockKind::MagentaCarpet
+            | BlockKind::LightBlueCarpet
+            | BlockKind::YellowCarpet
+            | BlockKind::LimeCarpet
+            | BlockKind::PinkCarpet
+            | BlockKind::GrayCarpet
+            | BlockKind::LightGrayCarpet
+            | BlockKind::CyanCarpet
+            | BlockKind::PurpleCarpet
+            | BlockKind::BlueCarpet
+            | BlockKind::BrownCarpet
+            | BlockKind::GreenCarpet
+            | BlockKind::RedCarpet
+            | BlockKind::BlackCarpet
+            | BlockKind::ShulkerBox
+            | BlockKind::WhiteShulkerBox
+            | BlockKind::OrangeShulkerBox
+            | BlockKind::MagentaShulkerBox
+            | BlockKind::LightBlueShulkerBox
+            | BlockKind::YellowShulkerBox
+            | BlockKind::LimeShulkerBox
+            | BlockKind::PinkShulkerBox
+            | BlockKind::GrayShulkerBox
+            | BlockKind::LightGrayShulkerBox
+            | BlockKind::CyanShulkerBox
+            | BlockKind::PurpleShulkerBox
+            | BlockKind::BlueShulkerBox
+            | BlockKind::BrownShulkerBox
+            | BlockKind::GreenShulkerBox
+            | BlockKind::RedShulkerBox
+            | BlockKind::BlackShulkerBox
+            | BlockKind::OakDoor
+            | BlockKind::SpruceDoor
+            | BlockKind::BirchDoor
+            | BlockKind::JungleDoor
+            | BlockKind::AcaciaDoor
+            | BlockKind::DarkOakDoor
+            | BlockKind::IronDoor
+            | BlockKind::StoneButton
+            | BlockKind::OakButton
+            | BlockKind::SpruceButton
+            | BlockKind::BirchButton
+            | BlockKind::JungleButton
+            | BlockKind::AcaciaButton
+            | BlockKind::DarkOakButton
+            | BlockKind::StonePressurePlate
+            | BlockKind::OakPressurePlate
+            | BlockKind::SprucePressurePlate
+            | BlockKind::BirchPressurePlate
+            | BlockKind::JunglePressurePlate
+            | BlockKind::AcaciaPressurePlate
+            | BlockKind::DarkOakPressurePlate
+            | BlockKind::LightWeightedPressurePlate
+            | BlockKind::HeavyWeightedPressurePlate
+            | BlockKind::Torch
+            | BlockKind::WallTorch
+            | BlockKind::RedstoneTorch
+            | BlockKind::RedstoneWallTorch
+            | BlockKind::RedstoneWire
+            | BlockKind::Repeater
+            | BlockKind::Comparator
+            | BlockKind::Lever
+            | BlockKind::Ladder
+            | BlockKind::TripwireHook
+            | BlockKind::Tripwire
+            | BlockKind::Cobweb
+            | BlockKind::Fire
+            | BlockKind::Cake
+            | BlockKind::DragonEgg
+            | BlockKind::TurtleEgg
+            | BlockKind::FlowerPot
+            | BlockKind::PottedOakSapling
+            | BlockKind::PottedSpruceSapling
+            | BlockKind::PottedBirchSapling
+            | BlockKind::PottedJungleSapling
+            | BlockKind::PottedAcaciaSapling
+            | BlockKind::PottedDarkOakSapling
+            | BlockKind::PottedFern
+            | BlockKind::PottedDandelion
+            | BlockKind::PottedPoppy
+            | BlockKind::PottedBlueOrchid
+            | BlockKind::PottedAllium
+            | BlockKind::PottedAzureBluet
+            | BlockKind::PottedRedTulip
+            | BlockKind::PottedOrangeTulip
+            | BlockKind::PottedWhiteTulip
+            | BlockKind::PottedPinkTulip
+            | BlockKind::PottedOxeyeDaisy
+            | BlockKind::PottedRedMushroom
+            | BlockKind::PottedBrownMushroom
+            | BlockKind::PottedDeadBush
+            | BlockKind::PottedCactus
+            | BlockKind::SkeletonSkull
+            | BlockKind::SkeletonWallSkull
+            | BlockKind::WitherSkeletonSkull
+            | BlockKind::WitherSkeletonWallSkull
+            | BlockKind::ZombieHead
+            | BlockKind::ZombieWallHead
+            | BlockKind::PlayerHead
+            | BlockKind::PlayerWallHead
+            | BlockKind::CreeperHead
+            | BlockKind::CreeperWallHead
+            | BlockKind::DragonHead
+            | BlockKind::DragonWallHead
+            | BlockKind::BubbleColumn => PistonBehavior::Destroy,
+            BlockKind::Cactus
+            | BlockKind::HayBlock
+            | BlockKind::NetherWartBlock
+            | BlockKind::DriedKelpBlock
+            | BlockKind::SnowBlock => PistonBehavior::Normal,
+            _ => match self.material() {
+                BlockMaterial::Air => PistonBehavior::Ignore,
+                BlockMaterial::Plant
+                | BlockMaterial::Leaves
+                | BlockMaterial::Water
+                | BlockMaterial::Lava
+                | BlockMaterial::Snow => PistonBehavior::Destroy,
+                _ => PistonBehavior::Normal,
+            },
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{BlockKind, BlockMaterial};
+
+/// The group of sounds a block plays when it is
+/// placed, broken, stepped on or hit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockSoundGroup {
+    Stone,
+    Wood,
+    Gravel,
+    Grass,
+    Metal,
+    Glass,
+    Wool,
+    Sand,
+    Snow,
+    Ladder,
+    Anvil,
+    Slime,
+}
+
+impl BlockKind {
+    /// Returns the sound group of this block kind.
+    ///
+    /// Most blocks use the sounds of their material;
+    /// exceptions are listed explicitly.
+    pub const fn sound_group(self) -> BlockSoundGroup {
+        match self {
+            BlockKind::GrassBlock
+            | BlockKind::Mycelium
+            | BlockKind::Sponge
+            | BlockKind::WetSponge => BlockSoundGroup::Grass,
+            BlockKind::Gravel => BlockSoundGroup::Gravel,
+            BlockKind::Sand | BlockKind::RedSand | BlockKind::SoulSand => BlockSoundGroup::Sand,
+            BlockKind::Anvil | BlockKind::ChippedAnvil | BlockKind::DamagedAnvil => {
+                BlockSoundGroup::Anvil
+            }
+            BlockKind::Ladder => BlockSoundGroup::Ladder,
+            BlockKind::SlimeBlock => BlockSoundGroup::Slime,
+            _ => match self.material() {
+                BlockMaterial::Wood => BlockSoundGroup::Wood,
+                BlockMaterial::Dirt => BlockSoundGroup::Gravel,
+                BlockMaterial::Sand => BlockSoundGroup::Sand,
+                BlockMaterial::Metal => BlockSoundGroup::Metal,
+                BlockMaterial::Glass | BlockMaterial::Ice => BlockSoundGroup::Glass,
+                BlockMaterial::Wool => BlockSoundGroup::Wool,
+                BlockMaterial::Plant | BlockMaterial::Leaves => BlockSoundGroup::Grass,
+                BlockMaterial::Snow => BlockSoundGroup::Snow,
+                _ => BlockSoundGroup::Stone,
+            },
+        }
+    }
+}
@@ -1,9 +1,11 @@
 //! Module for creating and modifying inventories of any type.
 
 use feather_items::{Item, ItemStack};
+use num_derive::{FromPrimitive, ToPrimitive};
 use once_cell::sync::Lazy;
-use smallvec::{Array, SmallVec};
+use smallvec::{smallvec, Array, SmallVec};
 use std::cmp::min;
+use std::convert::TryFrom;
 
 pub type SlotIndex = usize;
 
@@ -28,8 +30,16 @@ pub const SLOT_OFFHAND: SlotIndex = 45;
 pub const SLOT_INVENTORY_OFFSET: SlotIndex = 9;
 pub const SLOT_HOTBAR_OFFSET: SlotIndex = 36;
 
+pub const CRAFTING_SIZE: SlotIndex = 5;
+pub const ARMOR_SIZE: SlotIndex = 4;
 pub const HOTBAR_SIZE: SlotIndex = 9;
 pub const INVENTORY_SIZE: SlotIndex = 27;
+pub const OFFHAND_SIZE: SlotIndex = 1;
+
+/// The number of slots in a player inventory: the crafting grid,
+/// armor, main inventory, hotbar and offhand, in slot order.
+pub const PLAYER_INVENTORY_SIZE: SlotIndex =
+    CRAFTING_SIZE + ARMOR_SIZE + INVENTORY_SIZE + HOTBAR_SIZE + OFFHAND_SIZE;
 
 pub const SLOT_ENTITY_EQUIPMENT_MAIN_HAND: SlotIndex = 0;
 pub const SLOT_ENTITY_EQUIPMENT_OFF_HAND: SlotIndex = 1;
@@ -64,154 +74,147 @@ pub fn armor_slot_to_entity_equipment(slot: SlotIndex) -> SlotIndex {
     }
 }
 
+/// An equipment slot, with variants
+/// listed in the order of the Entity Equipment
+/// IDs. Convert to an ID with `From` and back
+/// with `Equipment::from_i32`.
+#[derive(Debug, Clone, Copy, ToPrimitive, FromPrimitive, PartialEq, Eq, Hash)]
+pub enum Equipment {
+    MainHand,
+    OffHand,
+    Boots,
+    Leggings,
+    Chestplate,
+    Helmet,
+}
+
+impl Equipment {
+    /// Returns the equipment slot with the given Entity Equipment
+    /// ID, or `None` if the ID is out of range.
+    pub fn from_i32(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Equipment::MainHand),
+            1 => Some(Equipment::OffHand),
+            2 => Some(Equipment::Boots),
+            3 => Some(Equipment::Leggings),
+            4 => Some(Equipment::Chestplate),
+            5 => Some(Equipment::Helmet),
+            _ => None,
+        }
+    }
+
+    /// Returns the equipment slot with the given Entity Equipment
+    /// ID, or `None` if the ID is out of range.
+    pub fn from_u32(id: u32) -> Option<Self> {
+        i32::try_from(id).ok().and_then(Equipment::from_i32)
+    }
+
+    pub fn from_slot_index(index: SlotIndex) -> Option<Self> {
+        match index {
+            SLOT_OFFHAND => Some(Equipment::OffHand),
+            SLOT_ARMOR_FEET => Some(Equipment::Boots),
+            SLOT_ARMOR_LEGS => Some(Equipment::Leggings),
+            SLOT_ARMOR_CHEST => Some(Equipment::Chestplate),
+            SLOT_ARMOR_HEAD => Some(Equipment::Helmet),
+            _ => None,
+        }
+    }
+
+    pub fn slot_index(self, held_item: SlotIndex) -> SlotIndex {
+        match self {
+            Equipment::MainHand => held_item + SLOT_HOTBAR_OFFSET,
+            Equipment::OffHand => SLOT_OFFHAND,
+            Equipment::Boots => SLOT_ARMOR_FEET,
+            Equipment::Leggings => SLOT_ARMOR_LEGS,
+            Equipment::Chestplate => SLOT_ARMOR_CHEST,
+            Equipment::Helmet => SLOT_ARMOR_HEAD,
+        }
+    }
+}
+
+impl From<Equipment> for i32 {
+    fn from(equipment: Equipment) -> Self {
+        equipment as i32
+    }
+}
+
+impl From<Equipment> for u32 {
+    fn from(equipment: Equipment) -> Self {
+        equipment as u32
+    }
+}
+
+/// The region of a player inventory to which a slot belongs.
+///
+/// The contained index is relative to the start of the region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InventorySlot {
+    /// The crafting output (index 0) or one of the four crafting inputs.
+    Crafting(u8),
+    /// One of the four armor slots.
+    Armor(Equipment),
+    /// The main inventory, excluding the hotbar.
+    Main(u8),
+    /// The hotbar.
+    Hotbar(u8),
+    /// The offhand slot.
+    OffHand,
+}
+
+/// Determines which region of a player inventory the given
+/// slot index belongs to.
+///
+/// Returns `None` if the index is outside of the player inventory.
+pub fn classify(slot: SlotIndex) -> Option<InventorySlot> {
+    match slot {
+        SLOT_CRAFTING_OUTPUT..=SLOT_CRAFTING_INPUT_X1_Y1 => {
+            Some(InventorySlot::Crafting(slot as u8))
+        }
+        SLOT_ARMOR_MIN..=SLOT_ARMOR_MAX => {
+            Equipment::from_slot_index(slot).map(InventorySlot::Armor)
+        }
+        x if (SLOT_INVENTORY_OFFSET..SLOT_INVENTORY_OFFSET + INVENTORY_SIZE).contains(&x) => {
+            Some(InventorySlot::Main((slot - SLOT_INVENTORY_OFFSET) as u8))
+        }
+        x if (SLOT_HOTBAR_OFFSET..SLOT_HOTBAR_OFFSET + HOTBAR_SIZE).contains(&x) => {
+            Some(InventorySlot::Hotbar((slot - SLOT_HOTBAR_OFFSET) as u8))
+        }
+        SLOT_OFFHAND => Some(InventorySlot::OffHand),
+        _ => None,
+    }
+}
+
+/// A region of a player inventory which can hold
+/// arbitrary items, used when searching for slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InventorySlotRegion {
+    /// The hotbar.
+    Hotbar,
+    /// The main inventory, excluding the hotbar.
+    Main,
+    /// The hotbar followed by the main inventory.
+    Storage,
+}
+
+impl InventorySlotRegion {
+    /// Returns the slot indices in this region, in search order.
+    pub fn slots(self) -> impl Iterator<Item = SlotIndex> {
+        let hotbar = SLOT_HOTBAR_OFFSET..SLOT_HOTBAR_OFFSET + HOTBAR_SIZE;
+        let main = SLOT_INVENTORY_OFFSET..SLOT_INVENTORY_OFFSET + INVENTORY_SIZE;
+
+        let (first, second) = match self {
+            InventorySlotRegion::Hotbar => (hotbar, 0..0),
+            InventorySlotRegion::Main => (main, 0..0),
+            InventorySlotRegion::Storage => (hotbar, main),
+        };
+        first.chain(second)
+    }
+}
+
 /// Returns the max size of a stack with the given
 /// type.
 pub fn max_size(item: Item) -> u8 {
-    match item {
-        Item::WoodenSword
-        | Item::GoldenSword
-        | Item::StoneSword
-        | Item::IronSword
-        | Item::DiamondSword
-        | Item::WoodenAxe
-        | Item::GoldenAxe
-        | Item::StoneAxe
-        | Item::IronAxe
-        | Item::DiamondAxe
-        | Item::WoodenHoe
-        | Item::GoldenHoe
-        | Item::StoneHoe
-        | Item::IronHoe
-        | Item::DiamondHoe
-        | Item::WoodenPickaxe
-        | Item::GoldenPickaxe
-        | Item::StonePickaxe
-        | Item::IronPickaxe
-        | Item::DiamondPickaxe
-        | Item::WoodenShovel
-        | Item::GoldenShovel
-        | Item::StoneShovel
-        | Item::IronShovel
-        | Item::DiamondShovel
-        | Item::LeatherChestplate
-        | Item::GoldenChestplate
-        | Item::ChainmailChestplate
-        | Item::IronChestplate
-        | Item::DiamondChestplate
-        | Item::LeatherLeggings
-        | Item::GoldenLeggings
-        | Item::ChainmailLeggings
-        | Item::IronLeggings
-        | Item::DiamondLeggings
-        | Item::LeatherBoots
-        | Item::GoldenBoots
-        | Item::ChainmailBoots
-        | Item::IronBoots
-        | Item::DiamondBoots
-        | Item::LeatherHelmet
-        | Item::GoldenHelmet
-        | Item::ChainmailHelmet
-        | Item::IronHelmet
-        | Item::DiamondHelmet
-        | Item::Bow
-        | Item::WritableBook
-        | Item::FlintAndSteel
-        | Item::WhiteBed
-        | Item::OrangeBed
-        | Item::MagentaBed
-        | Item::LightBlueBed
-        | Item::YellowBed
-        | Item::LimeBed
-        | Item::PinkBed
-        | Item::GrayBed
-        | Item::LightGrayBed
-        | Item::CyanBed
-        | Item::PurpleBed
-        | Item::BlueBed
-        | Item::BrownBed
-        | Item::GreenBed
-        | Item::RedBed
-        | Item::BlackBed
-        | Item::ShulkerBox
-        | Item::TurtleEgg
-        | Item::TurtleHelmet
-        | Item::FishingRod
-        | Item::EnchantedBook
-        | Item::Potion
-        | Item::LingeringPotion
-        | Item::SplashPotion
-        | Item::WaterBucket
-        | Item::LavaBucket
-        | Item::TropicalFishBucket
-        | Item::CodBucket
-        | Item::MilkBucket
-        | Item::PufferfishBucket
-        | Item::SalmonBucket
-        | Item::CarrotOnAStick
-        | Item::Elytra
-        | Item::Shield
-        | Item::Trident
-        | Item::MusicDisc13
-        | Item::MusicDiscCat
-        | Item::MusicDiscBlocks
-        | Item::MusicDiscChirp
-        | Item::MusicDiscFar
-        | Item::MusicDiscMall
-        | Item::MusicDiscMellohi
-        | Item::MusicDiscStal
-        | Item::MusicDiscStrad
-        | Item::MusicDiscWard
-        | Item::MusicDisc11
-        | Item::MusicDiscWait
-        | Item::TotemOfUndying
-        | Item::Shears
-        | Item::AcaciaBoat
-        | Item::DarkOakBoat
-        | Item::OakBoat
-        | Item::SpruceBoat
-        | Item::BirchBoat
-        | Item::JungleBoat
-        | Item::MushroomStew
-        | Item::BeetrootSoup
-        | Item::RabbitStew
-        | Item::Cake
-        | Item::Minecart
-        | Item::ChestMinecart
-        | Item::CommandBlockMinecart
-        | Item::FurnaceMinecart
-        | Item::HopperMinecart
-        | Item::TntMinecart
-        | Item::DiamondHorseArmor
-        | Item::GoldenHorseArmor
-        | Item::Saddle
-        | Item::KnowledgeBook
-        | Item::DebugStick
-        | Item::IronHorseArmor => 1,
-        Item::EnderPearl
-        | Item::Snowball
-        | Item::WhiteBanner
-        | Item::OrangeBanner
-        | Item::MagentaBanner
-        | Item::LightBlueBanner
-        | Item::YellowBanner
-        | Item::LimeBanner
-        | Item::PinkBanner
-        | Item::GrayBanner
-        | Item::LightGrayBanner
-        | Item::CyanBanner
-        | Item::PurpleBanner
-        | Item::BlueBanner
-        | Item::BrownBanner
-        | Item::GreenBanner
-        | Item::RedBanner
-        | Item::BlackBanner
-        | Item::Sign
-        | Item::ArmorStand
-        | Item::Bucket
-        | Item::WrittenBook
-        | Item::Egg => 16,
-        _ => 64,
-    }
+    item.stack_size()
 }
 
 /// The various types of inventories ("windows").
@@ -267,6 +270,13 @@ impl Inventory {
         self.items[index].as_ref()
     }
 
+    /// Retrieves a reference to the item at the given slot index,
+    /// or `None` if the slot is empty or the index is not below
+    /// `size()`.
+    pub fn try_item_at(&self, index: SlotIndex) -> Option<&ItemStack> {
+        self.items.get(index).and_then(Option::as_ref)
+    }
+
     pub fn item_at_mut(&mut self, index: SlotIndex) -> Option<&mut ItemStack> {
         self.items[index].as_mut()
     }
@@ -280,12 +290,159 @@ impl Inventory {
         }
     }
 
+    /// Applies a batch of slot changes, setting each slot to
+    /// the given item or clearing it for `None`. Later changes
+    /// to the same slot override earlier ones.
+    ///
+    /// Returns the affected slots, each listed once, so that a
+    /// single `InventoryUpdateEvent` can be triggered.
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    pub fn set_many(
+        &mut self,
+        items: impl IntoIterator<Item = (SlotIndex, Option<ItemStack>)>,
+    ) -> SmallVec<[SlotIndex; 2]> {
+        let mut affected_slots = SmallVec::new();
+
+        for (index, item) in items {
+            match item {
+                Some(item) => self.set_item_at(index, item),
+                None => self.items[index] = None,
+            }
+
+            if !affected_slots.contains(&index) {
+                affected_slots.push(index);
+            }
+        }
+
+        affected_slots
+    }
+
     /// Clears the item at the given slot index, returning
     /// the old item.
     pub fn clear_item_at(&mut self, index: SlotIndex) -> Option<ItemStack> {
         self.items[index].take()
     }
 
+    /// Clears every slot in this inventory.
+    ///
+    /// Returns the slots which were occupied before clearing,
+    /// so that a single `InventoryUpdateEvent` can be triggered.
+    pub fn clear(&mut self) -> SmallVec<[SlotIndex; 2]> {
+        self.items
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.take().map(|_| index))
+            .collect()
+    }
+
+    /// Returns an iterator over the occupied slots
+    /// of this inventory, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (SlotIndex, &ItemStack)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.as_ref().map(|item| (index, item)))
+    }
+
+    /// Returns an iterator over every slot of this
+    /// inventory, including empty ones, in index order.
+    pub fn iter_all(&self) -> impl Iterator<Item = (SlotIndex, Option<&ItemStack>)> {
+        self.items.iter().map(Option::as_ref).enumerate()
+    }
+
+    /// Exchanges the contents of two slots.
+    ///
+    /// Returns the swapped slots, so that a single
+    /// `InventoryUpdateEvent` can be triggered. If either
+    /// index is out of bounds, nothing is swapped and no
+    /// slots are returned.
+    pub fn swap(&mut self, a: SlotIndex, b: SlotIndex) -> SmallVec<[SlotIndex; 2]> {
+        if a >= self.items.len() || b >= self.items.len() {
+            return SmallVec::new();
+        }
+
+        self.items.swap(a, b);
+        smallvec![a, b]
+    }
+
+    /// Returns the first empty slot in the given region
+    /// of a player inventory.
+    pub fn first_empty_slot(&self, region: InventorySlotRegion) -> Option<SlotIndex> {
+        region.slots().find(|slot| self.item_at(*slot).is_none())
+    }
+
+    /// Returns the first slot in the given region of a player
+    /// inventory which holds the given item type.
+    ///
+    /// Stacks which are not yet full are preferred over full ones.
+    pub fn first_slot_with(&self, item: Item, region: InventorySlotRegion) -> Option<SlotIndex> {
+        let mut full = None;
+
+        for slot in region.slots() {
+            match self.item_at(slot) {
+                Some(stack) if stack.ty == item => {
+                    if stack.amount < max_size(item) {
+                        return Some(slot);
+                    }
+                    full.get_or_insert(slot);
+                }
+                _ => (),
+            }
+        }
+
+        full
+    }
+
+    /// Returns the total number of items of the given
+    /// type in this inventory.
+    pub fn count(&self, item: Item) -> u32 {
+        self.items
+            .iter()
+            .flatten()
+            .filter(|stack| stack.ty == item)
+            .map(|stack| u32::from(stack.amount))
+            .sum()
+    }
+
+    /// Removes up to `count` items of the given type from
+    /// this inventory.
+    ///
+    /// Items are taken from the smallest stacks first, so that
+    /// as few slots as possible are left partially filled.
+    ///
+    /// Returns the affected slots and the number of items
+    /// which were actually removed.
+    pub fn remove(&mut self, item: Item, count: u32) -> (SmallVec<[SlotIndex; 2]>, u32) {
+        let mut slots: Vec<(SlotIndex, u8)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match slot {
+                Some(stack) if stack.ty == item => Some((index, stack.amount)),
+                _ => None,
+            })
+            .collect();
+        slots.sort_by_key(|(_, amount)| *amount);
+
+        let mut affected_slots = SmallVec::new();
+        let mut removed = 0;
+
+        for (slot, amount) in slots {
+            if removed == count {
+                break;
+            }
+
+            let taken = min(u32::from(amount), count - removed) as u8;
+            self.set_item_at(slot, ItemStack::new(item, amount - taken));
+            affected_slots.push(slot);
+            removed += u32::from(taken);
+        }
+
+        (affected_slots, removed)
+    }
+
     /// Attempts to insert the given item into a player
     /// inventory.
     ///
@@ -297,7 +454,7 @@ impl Inventory {
         // First, look for slots already having the type.
         for slot in COLLECT_SEARCH_ORDER.iter() {
             if let Some(slot_item) = self.item_at(*slot).cloned() {
-                if slot_item.ty == item.ty {
+                if slot_item.is_stackable_with(&item) {
                     self.add_to_stack(&mut item, slot_item, *slot, &mut affected_slots);
 
                     if item.amount == 0 {
@@ -318,7 +475,7 @@ impl Inventory {
             }
 
             if let Some(slot_item) = slot_item {
-                if slot_item.ty == item.ty {
+                if slot_item.is_stackable_with(&item) {
                     self.add_to_stack(&mut item, slot_item, *slot, &mut affected_slots);
 
                     if item.amount == 0 {
@@ -351,6 +508,12 @@ impl Inventory {
         self.items.len() as u16
     }
 
+    /// Returns the number of slots in this inventory. Slot
+    /// indices below this value are in bounds.
+    pub fn size(&self) -> usize {
+        self.items.len()
+    }
+
     /// Returns a reference to this inventory's items.
     pub fn items(&self) -> &[Option<ItemStack>] {
         &self.items
@@ -380,6 +543,298 @@ mod tests {
         assert!(inv.item_at(0).is_none());
     }
 
+    #[test]
+    fn test_try_item_at() {
+        let mut inv = Inventory::new(InventoryType::Chest, 27);
+        inv.set_item_at(26, ItemStack::new(Item::Stone, 1));
+
+        assert_eq!(inv.try_item_at(26), Some(&ItemStack::new(Item::Stone, 1)));
+        assert_eq!(inv.try_item_at(0), None);
+        assert_eq!(inv.try_item_at(27), None);
+        assert_eq!(inv.try_item_at(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut inv = Inventory::new(InventoryType::Chest, 27);
+        inv.set_item_at(20, ItemStack::new(Item::Dirt, 3));
+        inv.set_item_at(2, ItemStack::new(Item::Stone, 1));
+
+        let occupied: Vec<_> = inv.iter().collect();
+        assert_eq!(
+            occupied,
+            vec![
+                (2, &ItemStack::new(Item::Stone, 1)),
+                (20, &ItemStack::new(Item::Dirt, 3)),
+            ]
+        );
+
+        assert_eq!(inv.iter_all().count(), 27);
+        assert_eq!(inv.iter_all().nth(2), Some((2, inv.item_at(2))));
+        assert_eq!(inv.iter_all().nth(3), Some((3, None)));
+    }
+
+    #[test]
+    fn test_size() {
+        let inv = Inventory::new(InventoryType::Player, PLAYER_INVENTORY_SIZE as u32);
+        assert_eq!(
+            inv.size(),
+            CRAFTING_SIZE + ARMOR_SIZE + INVENTORY_SIZE + HOTBAR_SIZE + OFFHAND_SIZE
+        );
+        assert_eq!(inv.size(), 46);
+
+        // Regions are laid out back to back.
+        assert_eq!(SLOT_ARMOR_MIN, CRAFTING_SIZE);
+        assert_eq!(SLOT_INVENTORY_OFFSET, SLOT_ARMOR_MIN + ARMOR_SIZE);
+        assert_eq!(SLOT_HOTBAR_OFFSET, SLOT_INVENTORY_OFFSET + INVENTORY_SIZE);
+        assert_eq!(SLOT_OFFHAND, SLOT_HOTBAR_OFFSET + HOTBAR_SIZE);
+        assert_eq!(classify(inv.size()), None);
+    }
+
+    #[test]
+    fn test_set_many() {
+        let mut inv = Inventory::new(InventoryType::Chest, 27);
+        inv.set_item_at(3, ItemStack::new(Item::Dirt, 3));
+
+        let slots = inv.set_many(vec![
+            (0, Some(ItemStack::new(Item::Stone, 1))),
+            (3, None),
+            (10, Some(ItemStack::new(Item::Stick, 16))),
+        ]);
+
+        assert_eq!(slots.as_slice(), &[0, 3, 10]);
+        assert_eq!(inv.item_at(0), Some(&ItemStack::new(Item::Stone, 1)));
+        assert_eq!(inv.item_at(3), None);
+        assert_eq!(inv.item_at(10), Some(&ItemStack::new(Item::Stick, 16)));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut inv = Inventory::new(InventoryType::Chest, 27);
+        inv.set_item_at(0, ItemStack::new(Item::Stone, 1));
+        inv.set_item_at(5, ItemStack::new(Item::Dirt, 3));
+
+        let slots = inv.swap(0, 5);
+        assert_eq!(slots.as_slice(), &[0, 5]);
+        assert_eq!(inv.item_at(0), Some(&ItemStack::new(Item::Dirt, 3)));
+        assert_eq!(inv.item_at(5), Some(&ItemStack::new(Item::Stone, 1)));
+
+        let slots = inv.swap(5, 10);
+        assert_eq!(slots.as_slice(), &[5, 10]);
+        assert_eq!(inv.item_at(5), None);
+        assert_eq!(inv.item_at(10), Some(&ItemStack::new(Item::Stone, 1)));
+
+        assert!(inv.swap(0, 27).is_empty());
+        assert_eq!(inv.item_at(0), Some(&ItemStack::new(Item::Dirt, 3)));
+    }
+
+    #[test]
+    fn test_first_empty_slot() {
+        let mut inv = Inventory::new(InventoryType::Player, 46);
+        assert_eq!(
+            inv.first_empty_slot(InventorySlotRegion::Hotbar),
+            Some(SLOT_HOTBAR_OFFSET)
+        );
+
+        inv.set_item_at(SLOT_HOTBAR_OFFSET, ItemStack::new(Item::Stone, 1));
+        inv.set_item_at(SLOT_HOTBAR_OFFSET + 1, ItemStack::new(Item::Stone, 1));
+        assert_eq!(
+            inv.first_empty_slot(InventorySlotRegion::Hotbar),
+            Some(SLOT_HOTBAR_OFFSET + 2)
+        );
+        assert_eq!(
+            inv.first_empty_slot(InventorySlotRegion::Main),
+            Some(SLOT_INVENTORY_OFFSET)
+        );
+
+        for slot in InventorySlotRegion::Hotbar.slots() {
+            inv.set_item_at(slot, ItemStack::new(Item::Stone, 1));
+        }
+        assert_eq!(inv.first_empty_slot(InventorySlotRegion::Hotbar), None);
+        assert_eq!(
+            inv.first_empty_slot(InventorySlotRegion::Storage),
+            Some(SLOT_INVENTORY_OFFSET)
+        );
+    }
+
+    #[test]
+    fn test_first_slot_with() {
+        let mut inv = Inventory::new(InventoryType::Player, 46);
+        assert_eq!(
+            inv.first_slot_with(Item::Stone, InventorySlotRegion::Storage),
+            None
+        );
+
+        inv.set_item_at(SLOT_HOTBAR_OFFSET + 1, ItemStack::new(Item::Stone, 64));
+        inv.set_item_at(SLOT_INVENTORY_OFFSET + 3, ItemStack::new(Item::Stone, 20));
+        inv.set_item_at(SLOT_HOTBAR_OFFSET, ItemStack::new(Item::Dirt, 5));
+
+        // The partial stack is preferred over the full one.
+        assert_eq!(
+            inv.first_slot_with(Item::Stone, InventorySlotRegion::Storage),
+            Some(SLOT_INVENTORY_OFFSET + 3)
+        );
+        // Only full stacks in the hotbar.
+        assert_eq!(
+            inv.first_slot_with(Item::Stone, InventorySlotRegion::Hotbar),
+            Some(SLOT_HOTBAR_OFFSET + 1)
+        );
+        assert_eq!(
+            inv.first_slot_with(Item::Dirt, InventorySlotRegion::Main),
+            None
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let mut inv = Inventory::new(InventoryType::Player, 46);
+        assert_eq!(inv.count(Item::Stone), 0);
+
+        inv.set_item_at(SLOT_HOTBAR_OFFSET, ItemStack::new(Item::Stone, 64));
+        inv.set_item_at(SLOT_INVENTORY_OFFSET, ItemStack::new(Item::Stone, 10));
+        inv.set_item_at(SLOT_OFFHAND, ItemStack::new(Item::Dirt, 3));
+
+        assert_eq!(inv.count(Item::Stone), 74);
+        assert_eq!(inv.count(Item::Dirt), 3);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut inv = Inventory::new(InventoryType::Player, 46);
+        inv.set_item_at(SLOT_HOTBAR_OFFSET, ItemStack::new(Item::Stone, 64));
+        inv.set_item_at(SLOT_INVENTORY_OFFSET, ItemStack::new(Item::Stone, 10));
+
+        // The smaller stack is consumed first.
+        let (slots, removed) = inv.remove(Item::Stone, 20);
+        assert_eq!(removed, 20);
+        assert_eq!(
+            slots.as_slice(),
+            &[SLOT_INVENTORY_OFFSET, SLOT_HOTBAR_OFFSET]
+        );
+        assert!(inv.item_at(SLOT_INVENTORY_OFFSET).is_none());
+        assert_eq!(inv.item_at(SLOT_HOTBAR_OFFSET).unwrap().amount, 54);
+
+        // Removing more than present removes everything.
+        let (slots, removed) = inv.remove(Item::Stone, 100);
+        assert_eq!(removed, 54);
+        assert_eq!(slots.as_slice(), &[SLOT_HOTBAR_OFFSET]);
+        assert_eq!(inv.count(Item::Stone), 0);
+
+        let (slots, removed) = inv.remove(Item::Stone, 1);
+        assert_eq!(removed, 0);
+        assert!(slots.is_empty());
+    }
+
+    #[test]
+    fn test_equipment_into_integer() {
+        let equipments = [
+            (Equipment::MainHand, 0),
+            (Equipment::OffHand, 1),
+            (Equipment::Boots, 2),
+            (Equipment::Leggings, 3),
+            (Equipment::Chestplate, 4),
+            (Equipment::Helmet, 5),
+        ];
+
+        for (equipment, id) in equipments.iter() {
+            assert_eq!(i32::from(*equipment), *id);
+            assert_eq!(u32::from(*equipment), *id as u32);
+        }
+    }
+
+    #[test]
+    fn test_equipment_from_i32() {
+        let equipments = [
+            Equipment::MainHand,
+            Equipment::OffHand,
+            Equipment::Boots,
+            Equipment::Leggings,
+            Equipment::Chestplate,
+            Equipment::Helmet,
+        ];
+
+        for equipment in &equipments {
+            assert_eq!(Equipment::from_i32(i32::from(*equipment)), Some(*equipment));
+            assert_eq!(Equipment::from_u32(u32::from(*equipment)), Some(*equipment));
+        }
+
+        assert_eq!(Equipment::from_i32(-1), None);
+        assert_eq!(Equipment::from_i32(6), None);
+        assert_eq!(Equipment::from_u32(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_equipment_slot_indices_distinct() {
+        let equipments = [
+            Equipment::MainHand,
+            Equipment::OffHand,
+            Equipment::Boots,
+            Equipment::Leggings,
+            Equipment::Chestplate,
+            Equipment::Helmet,
+        ];
+
+        let slots: Vec<SlotIndex> = equipments.iter().map(|e| e.slot_index(0)).collect();
+        for (i, slot) in slots.iter().enumerate() {
+            assert!(
+                !slots[i + 1..].contains(slot),
+                "{:?} shares slot {} with another equipment",
+                equipments[i],
+                slot
+            );
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let results = vec![
+            (SLOT_CRAFTING_OUTPUT, Some(InventorySlot::Crafting(0))),
+            (SLOT_CRAFTING_INPUT_X1_Y1, Some(InventorySlot::Crafting(4))),
+            (
+                SLOT_ARMOR_HEAD,
+                Some(InventorySlot::Armor(Equipment::Helmet)),
+            ),
+            (
+                SLOT_ARMOR_FEET,
+                Some(InventorySlot::Armor(Equipment::Boots)),
+            ),
+            (SLOT_INVENTORY_OFFSET, Some(InventorySlot::Main(0))),
+            (
+                SLOT_INVENTORY_OFFSET + INVENTORY_SIZE - 1,
+                Some(InventorySlot::Main(26)),
+            ),
+            (SLOT_HOTBAR_OFFSET, Some(InventorySlot::Hotbar(0))),
+            (
+                SLOT_HOTBAR_OFFSET + HOTBAR_SIZE - 1,
+                Some(InventorySlot::Hotbar(8)),
+            ),
+            (SLOT_OFFHAND, Some(InventorySlot::OffHand)),
+            (SLOT_OFFHAND + 1, None),
+            (10000, None),
+            (usize::MAX, None),
+        ];
+
+        for (slot, expected) in results {
+            assert_eq!(classify(slot), expected, "failed at {}", slot);
+        }
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut inv = Inventory::new(InventoryType::Player, 46);
+        inv.set_item_at(SLOT_HOTBAR_OFFSET, ItemStack::new(Item::Stone, 1));
+        inv.set_item_at(SLOT_ARMOR_HEAD, ItemStack::new(Item::DiamondHelmet, 1));
+        inv.set_item_at(SLOT_OFFHAND, ItemStack::new(Item::Shield, 1));
+
+        let cleared = inv.clear();
+        assert_eq!(
+            cleared.as_slice(),
+            &[SLOT_ARMOR_HEAD, SLOT_HOTBAR_OFFSET, SLOT_OFFHAND]
+        );
+        assert!(inv.items().iter().all(Option::is_none));
+        assert!(inv.clear().is_empty());
+    }
+
     #[test]
     fn test_collect_item_basic() {
         let mut inv = Inventory::new(InventoryType::Player, 46);
@@ -1,16 +1,26 @@
-use feather_blocks::BlockId;
+use feather_blocks::{BlockId, BlockKind};
 use feather_items::Item;
 
 mod mappings;
+mod tool;
+
+pub use tool::{is_effective, EffectiveBlocks};
 
 pub trait ItemToBlock {
     fn to_block(self) -> Option<BlockId>;
+
+    /// Returns the kind of block placed by this item, if any.
+    fn block(self) -> Option<BlockKind>;
 }
 
 impl ItemToBlock for Item {
     fn to_block(self) -> Option<BlockId> {
         mappings::item_to_block(self)
     }
+
+    fn block(self) -> Option<BlockKind> {
+        self.to_block().map(BlockId::kind)
+    }
 }
 
 pub trait BlockToItem {
@@ -23,9 +33,41 @@ impl BlockToItem for BlockId {
     }
 }
 
+impl BlockToItem for BlockKind {
+    fn to_item(self) -> Option<Item> {
+        mappings::kind_to_item(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use feather_blocks::BlockMaterial;
+    use feather_items::Tool;
+
+    #[test]
+    fn test_is_effective() {
+        assert!(is_effective(Tool::Pickaxe, BlockMaterial::Stone));
+        assert!(!is_effective(Tool::Pickaxe, BlockMaterial::Wood));
+        assert!(is_effective(Tool::Axe, BlockMaterial::Wood));
+        assert!(!is_effective(Tool::Axe, BlockMaterial::Stone));
+        assert!(is_effective(Tool::Shovel, BlockMaterial::Dirt));
+        assert!(!is_effective(Tool::Hoe, BlockMaterial::Dirt));
+    }
+
+    #[test]
+    fn test_effective_blocks() {
+        let blocks = Tool::Pickaxe.effective_blocks();
+        assert!(blocks.contains(&BlockKind::Stone));
+        assert!(!blocks.contains(&BlockKind::OakPlanks));
+        assert!(blocks.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for kind in blocks {
+            assert!(is_effective(Tool::Pickaxe, kind.material()));
+        }
+
+        assert!(Tool::Hoe.effective_blocks().is_empty());
+    }
 
     #[test]
     fn test_item_to_block() {
@@ -35,6 +77,7 @@ mod tests {
             Item::AcaciaWood,
             Item::Cobblestone,
             Item::Bone,
+            Item::Stick,
         ];
         let blocks = [
             None,
@@ -42,6 +85,7 @@ mod tests {
             Some(BlockId::acacia_wood()),
             Some(BlockId::cobblestone()),
             None,
+            None,
         ];
 
         for (item, block) in items.iter().zip(blocks.iter()) {
@@ -49,6 +93,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_item_block() {
+        assert_eq!(Item::Stone.block(), Some(BlockKind::Stone));
+        assert_eq!(Item::OakLog.block(), Some(BlockKind::OakLog));
+        assert_eq!(Item::Stick.block(), None);
+    }
+
     #[test]
     fn test_block_to_item() {
         let blocks = [
@@ -69,4 +120,11 @@ mod tests {
             assert_eq!(block.to_item(), Some(*item));
         }
     }
+
+    #[test]
+    fn test_block_kind_to_item() {
+        assert_eq!(BlockKind::Stone.to_item(), Some(Item::Stone));
+        assert_eq!(BlockKind::OakLog.to_item(), Some(Item::OakLog));
+        assert_eq!(BlockKind::Water.to_item(), None);
+    }
 }
@@ -524,7 +524,10 @@ pub fn item_to_block(item: Item) -> Option<BlockId> {
     }
 }
 pub fn block_to_item(block: BlockId) -> Option<Item> {
-    match block.kind() {
+    kind_to_item(block.kind())
+}
+pub fn kind_to_item(kind: BlockKind) -> Option<Item> {
+    match kind {
         BlockKind::Air => Some(Item::Air),
         BlockKind::Stone => Some(Item::Stone),
         BlockKind::Granite => Some(Item::Granite),
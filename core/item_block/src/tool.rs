@@ -0,0 +1,61 @@
+use feather_blocks::{BlockKind, BlockMaterial};
+use feather_items::Tool;
+use once_cell::sync::Lazy;
+
+const TOOLS: [Tool; 6] = [
+    Tool::Pickaxe,
+    Tool::Axe,
+    Tool::Shovel,
+    Tool::Hoe,
+    Tool::Sword,
+    Tool::Shears,
+];
+
+/// The blocks each tool in `TOOLS` is effective on,
+/// sorted by block kind.
+static EFFECTIVE_BLOCKS: Lazy<[Vec<BlockKind>; 6]> = Lazy::new(|| {
+    let mut lists: [Vec<BlockKind>; 6] = Default::default();
+
+    feather_blocks::for_each_block_kind!(|kind: BlockKind| {
+        for (tool, list) in TOOLS.iter().zip(lists.iter_mut()) {
+            if is_effective(*tool, kind.material()) {
+                list.push(kind);
+            }
+        }
+    });
+
+    lists
+});
+
+/// Returns whether the given tool is effective on blocks
+/// of the given material, i.e. whether it speeds up
+/// breaking them.
+pub fn is_effective(tool: Tool, material: BlockMaterial) -> bool {
+    match (tool, material) {
+        (Tool::Pickaxe, BlockMaterial::Stone)
+        | (Tool::Pickaxe, BlockMaterial::Metal)
+        | (Tool::Pickaxe, BlockMaterial::Ice)
+        | (Tool::Axe, BlockMaterial::Wood)
+        | (Tool::Shovel, BlockMaterial::Dirt)
+        | (Tool::Shovel, BlockMaterial::Sand)
+        | (Tool::Shovel, BlockMaterial::Snow)
+        | (Tool::Sword, BlockMaterial::Plant)
+        | (Tool::Sword, BlockMaterial::Leaves)
+        | (Tool::Shears, BlockMaterial::Leaves)
+        | (Tool::Shears, BlockMaterial::Wool) => true,
+        _ => false,
+    }
+}
+
+pub trait EffectiveBlocks {
+    /// Returns every block kind this tool is effective on,
+    /// sorted by block kind.
+    fn effective_blocks(self) -> &'static [BlockKind];
+}
+
+impl EffectiveBlocks for Tool {
+    fn effective_blocks(self) -> &'static [BlockKind] {
+        let index = TOOLS.iter().position(|tool| *tool == self).unwrap();
+        &EFFECTIVE_BLOCKS[index]
+    }
+}
@@ -0,0 +1,53 @@
+//! Nutritional values of food items.
+
+use crate::Item;
+
+/// The hunger and saturation restored by eating a food item.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FoodProperties {
+    /// Number of hunger points restored.
+    pub hunger: u32,
+    /// Number of saturation points restored.
+    pub saturation: f64,
+}
+
+impl FoodProperties {
+    const fn new(hunger: u32, saturation: f64) -> Self {
+        Self { hunger, saturation }
+    }
+}
+
+impl Item {
+    /// Returns the hunger and saturation restored by eating
+    /// this item, or `None` if it cannot be eaten.
+    pub fn food(self) -> Option<FoodProperties> {
+        let (hunger, saturation) = match self {
+            Item::Apple | Item::ChorusFruit => (4, 2.4),
+            Item::GoldenApple | Item::EnchantedGoldenApple => (4, 9.6),
+            Item::GoldenCarrot => (6, 14.4),
+            Item::Carrot => (3, 3.6),
+            Item::Potato | Item::DriedKelp => (1, 0.6),
+            Item::BakedPotato | Item::Bread => (5, 6.0),
+            Item::PoisonousPotato => (2, 1.2),
+            Item::Beetroot => (1, 1.2),
+            Item::MelonSlice => (2, 1.2),
+            Item::Cookie => (2, 0.4),
+            Item::PumpkinPie => (8, 4.8),
+            Item::BeetrootSoup | Item::MushroomStew => (6, 7.2),
+            Item::RabbitStew => (10, 12.0),
+            Item::Beef | Item::Porkchop | Item::Rabbit => (3, 1.8),
+            Item::CookedBeef | Item::CookedPorkchop => (8, 12.8),
+            Item::Chicken | Item::Mutton => (2, 1.2),
+            Item::CookedChicken => (6, 7.2),
+            Item::CookedMutton | Item::CookedSalmon => (6, 9.6),
+            Item::CookedRabbit | Item::CookedCod => (5, 6.0),
+            Item::Cod | Item::Salmon => (2, 0.4),
+            Item::Pufferfish | Item::TropicalFish => (1, 0.2),
+            Item::RottenFlesh => (4, 0.8),
+            Item::SpiderEye => (2, 3.2),
+            _ => return None,
+        };
+
+        Some(FoodProperties::new(hunger, saturation))
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::all)]
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ToPrimitive, FromPrimitive)]
 pub enum Item {
     Air,
@@ -791,6 +793,11 @@ pub enum Item {
     NautilusShell,
     HeartOfTheSea,
 }
+impl Default for Item {
+    fn default() -> Self {
+        Item::Air
+    }
+}
 impl Item {
     pub fn from_identifier(identifier: &str) -> Option<Self> {
         match identifier {
@@ -1587,7 +1594,7 @@ impl Item {
             _ => None,
         }
     }
-    pub fn identifier(self) -> &'static str {
+    pub const fn identifier(self) -> &'static str {
         match self {
             Item::Air => "minecraft:air",
             Item::Stone => "minecraft:stone",
@@ -2381,4 +2388,1606 @@ impl Item {
             Item::HeartOfTheSea => "minecraft:heart_of_the_sea",
         }
     }
+    #[doc = " Returns the translation key of this item."]
+    #[doc = ""]
+    #[doc = " Items which place a block share the block's key,"]
+    #[doc = " e.g. `block.minecraft.stone`; other items use"]
+    #[doc = " `item.minecraft.<name>`."]
+    pub const fn translation_key(self) -> &'static str {
+        match self {
+            Item::Air => "block.minecraft.air",
+            Item::Stone => "block.minecraft.stone",
+            Item::Granite => "block.minecraft.granite",
+            Item::PolishedGranite => "block.minecraft.polished_granite",
+            Item::Diorite => "block.minecraft.diorite",
+            Item::PolishedDiorite => "block.minecraft.polished_diorite",
+            Item::Andesite => "block.minecraft.andesite",
+            Item::PolishedAndesite => "block.minecraft.polished_andesite",
+            Item::GrassBlock => "block.minecraft.grass_block",
+            Item::Dirt => "block.minecraft.dirt",
+            Item::CoarseDirt => "block.minecraft.coarse_dirt",
+            Item::Podzol => "block.minecraft.podzol",
+            Item::Cobblestone => "block.minecraft.cobblestone",
+            Item::OakPlanks => "block.minecraft.oak_planks",
+            Item::SprucePlanks => "block.minecraft.spruce_planks",
+            Item::BirchPlanks => "block.minecraft.birch_planks",
+            Item::JunglePlanks => "block.minecraft.jungle_planks",
+            Item::AcaciaPlanks => "block.minecraft.acacia_planks",
+            Item::DarkOakPlanks => "block.minecraft.dark_oak_planks",
+            Item::OakSapling => "block.minecraft.oak_sapling",
+            Item::SpruceSapling => "block.minecraft.spruce_sapling",
+            Item::BirchSapling => "block.minecraft.birch_sapling",
+            Item::JungleSapling => "block.minecraft.jungle_sapling",
+            Item::AcaciaSapling => "block.minecraft.acacia_sapling",
+            Item::DarkOakSapling => "block.minecraft.dark_oak_sapling",
+            Item::Bedrock => "block.minecraft.bedrock",
+            Item::Sand => "block.minecraft.sand",
+            Item::RedSand => "block.minecraft.red_sand",
+            Item::Gravel => "block.minecraft.gravel",
+            Item::GoldOre => "block.minecraft.gold_ore",
+            Item::IronOre => "block.minecraft.iron_ore",
+            Item::CoalOre => "block.minecraft.coal_ore",
+            Item::OakLog => "block.minecraft.oak_log",
+            Item::SpruceLog => "block.minecraft.spruce_log",
+            Item::BirchLog => "block.minecraft.birch_log",
+            Item::JungleLog => "block.minecraft.jungle_log",
+            Item::AcaciaLog => "block.minecraft.acacia_log",
+            Item::DarkOakLog => "block.minecraft.dark_oak_log",
+            Item::StrippedOakLog => "block.minecraft.stripped_oak_log",
+            Item::StrippedSpruceLog => "block.minecraft.stripped_spruce_log",
+            Item::StrippedBirchLog => "block.minecraft.stripped_birch_log",
+            Item::StrippedJungleLog => "block.minecraft.stripped_jungle_log",
+            Item::StrippedAcaciaLog => "block.minecraft.stripped_acacia_log",
+            Item::StrippedDarkOakLog => "block.minecraft.stripped_dark_oak_log",
+            Item::StrippedOakWood => "block.minecraft.stripped_oak_wood",
+            Item::StrippedSpruceWood => "block.minecraft.stripped_spruce_wood",
+            Item::StrippedBirchWood => "block.minecraft.stripped_birch_wood",
+            Item::StrippedJungleWood => "block.minecraft.stripped_jungle_wood",
+            Item::StrippedAcaciaWood => "block.minecraft.stripped_acacia_wood",
+            Item::StrippedDarkOakWood => "block.minecraft.stripped_dark_oak_wood",
+            Item::OakWood => "block.minecraft.oak_wood",
+            Item::SpruceWood => "block.minecraft.spruce_wood",
+            Item::BirchWood => "block.minecraft.birch_wood",
+            Item::JungleWood => "block.minecraft.jungle_wood",
+            Item::AcaciaWood => "block.minecraft.acacia_wood",
+            Item::DarkOakWood => "block.minecraft.dark_oak_wood",
+            Item::OakLeaves => "block.minecraft.oak_leaves",
+            Item::SpruceLeaves => "block.minecraft.spruce_leaves",
+            Item::BirchLeaves => "block.minecraft.birch_leaves",
+            Item::JungleLeaves => "block.minecraft.jungle_leaves",
+            Item::AcaciaLeaves => "block.minecraft.acacia_leaves",
+            Item::DarkOakLeaves => "block.minecraft.dark_oak_leaves",
+            Item::Sponge => "block.minecraft.sponge",
+            Item::WetSponge => "block.minecraft.wet_sponge",
+            Item::Glass => "block.minecraft.glass",
+            Item::LapisOre => "block.minecraft.lapis_ore",
+            Item::LapisBlock => "block.minecraft.lapis_block",
+            Item::Dispenser => "block.minecraft.dispenser",
+            Item::Sandstone => "block.minecraft.sandstone",
+            Item::ChiseledSandstone => "block.minecraft.chiseled_sandstone",
+            Item::CutSandstone => "block.minecraft.cut_sandstone",
+            Item::NoteBlock => "block.minecraft.note_block",
+            Item::PoweredRail => "block.minecraft.powered_rail",
+            Item::DetectorRail => "block.minecraft.detector_rail",
+            Item::StickyPiston => "block.minecraft.sticky_piston",
+            Item::Cobweb => "block.minecraft.cobweb",
+            Item::Grass => "block.minecraft.grass",
+            Item::Fern => "block.minecraft.fern",
+            Item::DeadBush => "block.minecraft.dead_bush",
+            Item::Seagrass => "block.minecraft.seagrass",
+            Item::SeaPickle => "block.minecraft.sea_pickle",
+            Item::Piston => "block.minecraft.piston",
+            Item::WhiteWool => "block.minecraft.white_wool",
+            Item::OrangeWool => "block.minecraft.orange_wool",
+            Item::MagentaWool => "block.minecraft.magenta_wool",
+            Item::LightBlueWool => "block.minecraft.light_blue_wool",
+            Item::YellowWool => "block.minecraft.yellow_wool",
+            Item::LimeWool => "block.minecraft.lime_wool",
+            Item::PinkWool => "block.minecraft.pink_wool",
+            Item::GrayWool => "block.minecraft.gray_wool",
+            Item::LightGrayWool => "block.minecraft.light_gray_wool",
+            Item::CyanWool => "block.minecraft.cyan_wool",
+            Item::PurpleWool => "block.minecraft.purple_wool",
+            Item::BlueWool => "block.minecraft.blue_wool",
+            Item::BrownWool => "block.minecraft.brown_wool",
+            Item::GreenWool => "block.minecraft.green_wool",
+            Item::RedWool => "block.minecraft.red_wool",
+            Item::BlackWool => "block.minecraft.black_wool",
+            Item::Dandelion => "block.minecraft.dandelion",
+            Item::Poppy => "block.minecraft.poppy",
+            Item::BlueOrchid => "block.minecraft.blue_orchid",
+            Item::Allium => "block.minecraft.allium",
+            Item::AzureBluet => "block.minecraft.azure_bluet",
+            Item::RedTulip => "block.minecraft.red_tulip",
+            Item::OrangeTulip => "block.minecraft.orange_tulip",
+            Item::WhiteTulip => "block.minecraft.white_tulip",
+            Item::PinkTulip => "block.minecraft.pink_tulip",
+            Item::OxeyeDaisy => "block.minecraft.oxeye_daisy",
+            Item::BrownMushroom => "block.minecraft.brown_mushroom",
+            Item::RedMushroom => "block.minecraft.red_mushroom",
+            Item::GoldBlock => "block.minecraft.gold_block",
+            Item::IronBlock => "block.minecraft.iron_block",
+            Item::OakSlab => "block.minecraft.oak_slab",
+            Item::SpruceSlab => "block.minecraft.spruce_slab",
+            Item::BirchSlab => "block.minecraft.birch_slab",
+            Item::JungleSlab => "block.minecraft.jungle_slab",
+            Item::AcaciaSlab => "block.minecraft.acacia_slab",
+            Item::DarkOakSlab => "block.minecraft.dark_oak_slab",
+            Item::StoneSlab => "block.minecraft.stone_slab",
+            Item::SandstoneSlab => "block.minecraft.sandstone_slab",
+            Item::PetrifiedOakSlab => "block.minecraft.petrified_oak_slab",
+            Item::CobblestoneSlab => "block.minecraft.cobblestone_slab",
+            Item::BrickSlab => "block.minecraft.brick_slab",
+            Item::StoneBrickSlab => "block.minecraft.stone_brick_slab",
+            Item::NetherBrickSlab => "block.minecraft.nether_brick_slab",
+            Item::QuartzSlab => "block.minecraft.quartz_slab",
+            Item::RedSandstoneSlab => "block.minecraft.red_sandstone_slab",
+            Item::PurpurSlab => "block.minecraft.purpur_slab",
+            Item::PrismarineSlab => "block.minecraft.prismarine_slab",
+            Item::PrismarineBrickSlab => "block.minecraft.prismarine_brick_slab",
+            Item::DarkPrismarineSlab => "block.minecraft.dark_prismarine_slab",
+            Item::SmoothQuartz => "block.minecraft.smooth_quartz",
+            Item::SmoothRedSandstone => "block.minecraft.smooth_red_sandstone",
+            Item::SmoothSandstone => "block.minecraft.smooth_sandstone",
+            Item::SmoothStone => "block.minecraft.smooth_stone",
+            Item::Bricks => "block.minecraft.bricks",
+            Item::Tnt => "block.minecraft.tnt",
+            Item::Bookshelf => "block.minecraft.bookshelf",
+            Item::MossyCobblestone => "block.minecraft.mossy_cobblestone",
+            Item::Obsidian => "block.minecraft.obsidian",
+            Item::Torch => "block.minecraft.torch",
+            Item::EndRod => "block.minecraft.end_rod",
+            Item::ChorusPlant => "block.minecraft.chorus_plant",
+            Item::ChorusFlower => "block.minecraft.chorus_flower",
+            Item::PurpurBlock => "block.minecraft.purpur_block",
+            Item::PurpurPillar => "block.minecraft.purpur_pillar",
+            Item::PurpurStairs => "block.minecraft.purpur_stairs",
+            Item::Spawner => "block.minecraft.spawner",
+            Item::OakStairs => "block.minecraft.oak_stairs",
+            Item::Chest => "block.minecraft.chest",
+            Item::DiamondOre => "block.minecraft.diamond_ore",
+            Item::DiamondBlock => "block.minecraft.diamond_block",
+            Item::CraftingTable => "block.minecraft.crafting_table",
+            Item::Farmland => "block.minecraft.farmland",
+            Item::Furnace => "block.minecraft.furnace",
+            Item::Ladder => "block.minecraft.ladder",
+            Item::Rail => "block.minecraft.rail",
+            Item::CobblestoneStairs => "block.minecraft.cobblestone_stairs",
+            Item::Lever => "block.minecraft.lever",
+            Item::StonePressurePlate => "block.minecraft.stone_pressure_plate",
+            Item::OakPressurePlate => "block.minecraft.oak_pressure_plate",
+            Item::SprucePressurePlate => "block.minecraft.spruce_pressure_plate",
+            Item::BirchPressurePlate => "block.minecraft.birch_pressure_plate",
+            Item::JunglePressurePlate => "block.minecraft.jungle_pressure_plate",
+            Item::AcaciaPressurePlate => "block.minecraft.acacia_pressure_plate",
+            Item::DarkOakPressurePlate => "block.minecraft.dark_oak_pressure_plate",
+            Item::RedstoneOre => "block.minecraft.redstone_ore",
+            Item::RedstoneTorch => "block.minecraft.redstone_torch",
+            Item::StoneButton => "block.minecraft.stone_button",
+            Item::Snow => "block.minecraft.snow",
+            Item::Ice => "block.minecraft.ice",
+            Item::SnowBlock => "block.minecraft.snow_block",
+            Item::Cactus => "block.minecraft.cactus",
+            Item::Clay => "block.minecraft.clay",
+            Item::Jukebox => "block.minecraft.jukebox",
+            Item::OakFence => "block.minecraft.oak_fence",
+            Item::SpruceFence => "block.minecraft.spruce_fence",
+            Item::BirchFence => "block.minecraft.birch_fence",
+            Item::JungleFence => "block.minecraft.jungle_fence",
+            Item::AcaciaFence => "block.minecraft.acacia_fence",
+            Item::DarkOakFence => "block.minecraft.dark_oak_fence",
+            Item::Pumpkin => "block.minecraft.pumpkin",
+            Item::CarvedPumpkin => "block.minecraft.carved_pumpkin",
+            Item::Netherrack => "block.minecraft.netherrack",
+            Item::SoulSand => "block.minecraft.soul_sand",
+            Item::Glowstone => "block.minecraft.glowstone",
+            Item::JackOLantern => "block.minecraft.jack_o_lantern",
+            Item::OakTrapdoor => "block.minecraft.oak_trapdoor",
+            Item::SpruceTrapdoor => "block.minecraft.spruce_trapdoor",
+            Item::BirchTrapdoor => "block.minecraft.birch_trapdoor",
+            Item::JungleTrapdoor => "block.minecraft.jungle_trapdoor",
+            Item::AcaciaTrapdoor => "block.minecraft.acacia_trapdoor",
+            Item::DarkOakTrapdoor => "block.minecraft.dark_oak_trapdoor",
+            Item::InfestedStone => "block.minecraft.infested_stone",
+            Item::InfestedCobblestone => "block.minecraft.infested_cobblestone",
+            Item::InfestedStoneBricks => "block.minecraft.infested_stone_bricks",
+            Item::InfestedMossyStoneBricks => "block.minecraft.infested_mossy_stone_bricks",
+            Item::InfestedCrackedStoneBricks => "item.minecraft.infested_cracked_stone_bricks",
+            Item::InfestedChiseledStoneBricks => "item.minecraft.infested_chiseled_stone_bricks",
+            Item::StoneBricks => "block.minecraft.stone_bricks",
+            Item::MossyStoneBricks => "block.minecraft.mossy_stone_bricks",
+            Item::CrackedStoneBricks => "block.minecraft.cracked_stone_bricks",
+            Item::ChiseledStoneBricks => "block.minecraft.chiseled_stone_bricks",
+            Item::BrownMushroomBlock => "block.minecraft.brown_mushroom_block",
+            Item::RedMushroomBlock => "block.minecraft.red_mushroom_block",
+            Item::MushroomStem => "block.minecraft.mushroom_stem",
+            Item::IronBars => "block.minecraft.iron_bars",
+            Item::GlassPane => "block.minecraft.glass_pane",
+            Item::Melon => "block.minecraft.melon",
+            Item::Vine => "block.minecraft.vine",
+            Item::OakFenceGate => "block.minecraft.oak_fence_gate",
+            Item::SpruceFenceGate => "block.minecraft.spruce_fence_gate",
+            Item::BirchFenceGate => "block.minecraft.birch_fence_gate",
+            Item::JungleFenceGate => "block.minecraft.jungle_fence_gate",
+            Item::AcaciaFenceGate => "block.minecraft.acacia_fence_gate",
+            Item::DarkOakFenceGate => "block.minecraft.dark_oak_fence_gate",
+            Item::BrickStairs => "block.minecraft.brick_stairs",
+            Item::StoneBrickStairs => "block.minecraft.stone_brick_stairs",
+            Item::Mycelium => "block.minecraft.mycelium",
+            Item::LilyPad => "block.minecraft.lily_pad",
+            Item::NetherBricks => "block.minecraft.nether_bricks",
+            Item::NetherBrickFence => "block.minecraft.nether_brick_fence",
+            Item::NetherBrickStairs => "block.minecraft.nether_brick_stairs",
+            Item::EnchantingTable => "block.minecraft.enchanting_table",
+            Item::EndPortalFrame => "block.minecraft.end_portal_frame",
+            Item::EndStone => "block.minecraft.end_stone",
+            Item::EndStoneBricks => "block.minecraft.end_stone_bricks",
+            Item::DragonEgg => "block.minecraft.dragon_egg",
+            Item::RedstoneLamp => "block.minecraft.redstone_lamp",
+            Item::SandstoneStairs => "block.minecraft.sandstone_stairs",
+            Item::EmeraldOre => "block.minecraft.emerald_ore",
+            Item::EnderChest => "block.minecraft.ender_chest",
+            Item::TripwireHook => "block.minecraft.tripwire_hook",
+            Item::EmeraldBlock => "block.minecraft.emerald_block",
+            Item::SpruceStairs => "block.minecraft.spruce_stairs",
+            Item::BirchStairs => "block.minecraft.birch_stairs",
+            Item::JungleStairs => "block.minecraft.jungle_stairs",
+            Item::CommandBlock => "block.minecraft.command_block",
+            Item::Beacon => "block.minecraft.beacon",
+            Item::CobblestoneWall => "block.minecraft.cobblestone_wall",
+            Item::MossyCobblestoneWall => "block.minecraft.mossy_cobblestone_wall",
+            Item::OakButton => "block.minecraft.oak_button",
+            Item::SpruceButton => "block.minecraft.spruce_button",
+            Item::BirchButton => "block.minecraft.birch_button",
+            Item::JungleButton => "block.minecraft.jungle_button",
+            Item::AcaciaButton => "block.minecraft.acacia_button",
+            Item::DarkOakButton => "block.minecraft.dark_oak_button",
+            Item::Anvil => "block.minecraft.anvil",
+            Item::ChippedAnvil => "block.minecraft.chipped_anvil",
+            Item::DamagedAnvil => "block.minecraft.damaged_anvil",
+            Item::TrappedChest => "block.minecraft.trapped_chest",
+            Item::LightWeightedPressurePlate => "item.minecraft.light_weighted_pressure_plate",
+            Item::HeavyWeightedPressurePlate => "item.minecraft.heavy_weighted_pressure_plate",
+            Item::DaylightDetector => "block.minecraft.daylight_detector",
+            Item::RedstoneBlock => "block.minecraft.redstone_block",
+            Item::NetherQuartzOre => "block.minecraft.nether_quartz_ore",
+            Item::Hopper => "block.minecraft.hopper",
+            Item::ChiseledQuartzBlock => "block.minecraft.chiseled_quartz_block",
+            Item::QuartzBlock => "block.minecraft.quartz_block",
+            Item::QuartzPillar => "block.minecraft.quartz_pillar",
+            Item::QuartzStairs => "block.minecraft.quartz_stairs",
+            Item::ActivatorRail => "block.minecraft.activator_rail",
+            Item::Dropper => "block.minecraft.dropper",
+            Item::WhiteTerracotta => "block.minecraft.white_terracotta",
+            Item::OrangeTerracotta => "block.minecraft.orange_terracotta",
+            Item::MagentaTerracotta => "block.minecraft.magenta_terracotta",
+            Item::LightBlueTerracotta => "block.minecraft.light_blue_terracotta",
+            Item::YellowTerracotta => "block.minecraft.yellow_terracotta",
+            Item::LimeTerracotta => "block.minecraft.lime_terracotta",
+            Item::PinkTerracotta => "block.minecraft.pink_terracotta",
+            Item::GrayTerracotta => "block.minecraft.gray_terracotta",
+            Item::LightGrayTerracotta => "block.minecraft.light_gray_terracotta",
+            Item::CyanTerracotta => "block.minecraft.cyan_terracotta",
+            Item::PurpleTerracotta => "block.minecraft.purple_terracotta",
+            Item::BlueTerracotta => "block.minecraft.blue_terracotta",
+            Item::BrownTerracotta => "block.minecraft.brown_terracotta",
+            Item::GreenTerracotta => "block.minecraft.green_terracotta",
+            Item::RedTerracotta => "block.minecraft.red_terracotta",
+            Item::BlackTerracotta => "block.minecraft.black_terracotta",
+            Item::Barrier => "block.minecraft.barrier",
+            Item::IronTrapdoor => "block.minecraft.iron_trapdoor",
+            Item::HayBlock => "block.minecraft.hay_block",
+            Item::WhiteCarpet => "block.minecraft.white_carpet",
+            Item::OrangeCarpet => "block.minecraft.orange_carpet",
+            Item::MagentaCarpet => "block.minecraft.magenta_carpet",
+            Item::LightBlueCarpet => "block.minecraft.light_blue_carpet",
+            Item::YellowCarpet => "block.minecraft.yellow_carpet",
+            Item::LimeCarpet => "block.minecraft.lime_carpet",
+            Item::PinkCarpet => "block.minecraft.pink_carpet",
+            Item::GrayCarpet => "block.minecraft.gray_carpet",
+            Item::LightGrayCarpet => "block.minecraft.light_gray_carpet",
+            Item::CyanCarpet => "block.minecraft.cyan_carpet",
+            Item::PurpleCarpet => "block.minecraft.purple_carpet",
+            Item::BlueCarpet => "block.minecraft.blue_carpet",
+            Item::BrownCarpet => "block.minecraft.brown_carpet",
+            Item::GreenCarpet => "block.minecraft.green_carpet",
+            Item::RedCarpet => "block.minecraft.red_carpet",
+            Item::BlackCarpet => "block.minecraft.black_carpet",
+            Item::Terracotta => "block.minecraft.terracotta",
+            Item::CoalBlock => "block.minecraft.coal_block",
+            Item::PackedIce => "block.minecraft.packed_ice",
+            Item::AcaciaStairs => "block.minecraft.acacia_stairs",
+            Item::DarkOakStairs => "block.minecraft.dark_oak_stairs",
+            Item::SlimeBlock => "block.minecraft.slime_block",
+            Item::GrassPath => "block.minecraft.grass_path",
+            Item::Sunflower => "block.minecraft.sunflower",
+            Item::Lilac => "block.minecraft.lilac",
+            Item::RoseBush => "block.minecraft.rose_bush",
+            Item::Peony => "block.minecraft.peony",
+            Item::TallGrass => "block.minecraft.tall_grass",
+            Item::LargeFern => "block.minecraft.large_fern",
+            Item::WhiteStainedGlass => "block.minecraft.white_stained_glass",
+            Item::OrangeStainedGlass => "block.minecraft.orange_stained_glass",
+            Item::MagentaStainedGlass => "block.minecraft.magenta_stained_glass",
+            Item::LightBlueStainedGlass => "block.minecraft.light_blue_stained_glass",
+            Item::YellowStainedGlass => "block.minecraft.yellow_stained_glass",
+            Item::LimeStainedGlass => "block.minecraft.lime_stained_glass",
+            Item::PinkStainedGlass => "block.minecraft.pink_stained_glass",
+            Item::GrayStainedGlass => "block.minecraft.gray_stained_glass",
+            Item::LightGrayStainedGlass => "block.minecraft.light_gray_stained_glass",
+            Item::CyanStainedGlass => "block.minecraft.cyan_stained_glass",
+            Item::PurpleStainedGlass => "block.minecraft.purple_stained_glass",
+            Item::BlueStainedGlass => "block.minecraft.blue_stained_glass",
+            Item::BrownStainedGlass => "block.minecraft.brown_stained_glass",
+            Item::GreenStainedGlass => "block.minecraft.green_stained_glass",
+            Item::RedStainedGlass => "block.minecraft.red_stained_glass",
+            Item::BlackStainedGlass => "block.minecraft.black_stained_glass",
+            Item::WhiteStainedGlassPane => "block.minecraft.white_stained_glass_pane",
+            Item::OrangeStainedGlassPane => "block.minecraft.orange_stained_glass_pane",
+            Item::MagentaStainedGlassPane => "block.minecraft.magenta_stained_glass_pane",
+            Item::LightBlueStainedGlassPane => "block.minecraft.light_blue_stained_glass_pane",
+            Item::YellowStainedGlassPane => "block.minecraft.yellow_stained_glass_pane",
+            Item::LimeStainedGlassPane => "block.minecraft.lime_stained_glass_pane",
+            Item::PinkStainedGlassPane => "block.minecraft.pink_stained_glass_pane",
+            Item::GrayStainedGlassPane => "block.minecraft.gray_stained_glass_pane",
+            Item::LightGrayStainedGlassPane => "block.minecraft.light_gray_stained_glass_pane",
+            Item::CyanStainedGlassPane => "block.minecraft.cyan_stained_glass_pane",
+            Item::PurpleStainedGlassPane => "block.minecraft.purple_stained_glass_pane",
+            Item::BlueStainedGlassPane => "block.minecraft.blue_stained_glass_pane",
+            Item::BrownStainedGlassPane => "block.minecraft.brown_stained_glass_pane",
+            Item::GreenStainedGlassPane => "block.minecraft.green_stained_glass_pane",
+            Item::RedStainedGlassPane => "block.minecraft.red_stained_glass_pane",
+            Item::BlackStainedGlassPane => "block.minecraft.black_stained_glass_pane",
+            Item::Prismarine => "block.minecraft.prismarine",
+            Item::PrismarineBricks => "block.minecraft.prismarine_bricks",
+            Item::DarkPrismarine => "block.minecraft.dark_prismarine",
+            Item::PrismarineStairs => "block.minecraft.prismarine_stairs",
+            Item::PrismarineBrickStairs => "block.minecraft.prismarine_brick_stairs",
+            Item::DarkPrismarineStairs => "block.minecraft.dark_prismarine_stairs",
+            Item::SeaLantern => "block.minecraft.sea_lantern",
+            Item::RedSandstone => "block.minecraft.red_sandstone",
+            Item::ChiseledRedSandstone => "block.minecraft.chiseled_red_sandstone",
+            Item::CutRedSandstone => "block.minecraft.cut_red_sandstone",
+            Item::RedSandstoneStairs => "block.minecraft.red_sandstone_stairs",
+            Item::RepeatingCommandBlock => "block.minecraft.repeating_command_block",
+            Item::ChainCommandBlock => "block.minecraft.chain_command_block",
+            Item::MagmaBlock => "block.minecraft.magma_block",
+            Item::NetherWartBlock => "block.minecraft.nether_wart_block",
+            Item::RedNetherBricks => "block.minecraft.red_nether_bricks",
+            Item::BoneBlock => "block.minecraft.bone_block",
+            Item::StructureVoid => "block.minecraft.structure_void",
+            Item::Observer => "block.minecraft.observer",
+            Item::ShulkerBox => "block.minecraft.shulker_box",
+            Item::WhiteShulkerBox => "block.minecraft.white_shulker_box",
+            Item::OrangeShulkerBox => "block.minecraft.orange_shulker_box",
+            Item::MagentaShulkerBox => "block.minecraft.magenta_shulker_box",
+            Item::LightBlueShulkerBox => "block.minecraft.light_blue_shulker_box",
+            Item::YellowShulkerBox => "block.minecraft.yellow_shulker_box",
+            Item::LimeShulkerBox => "block.minecraft.lime_shulker_box",
+            Item::PinkShulkerBox => "block.minecraft.pink_shulker_box",
+            Item::GrayShulkerBox => "block.minecraft.gray_shulker_box",
+            Item::LightGrayShulkerBox => "block.minecraft.light_gray_shulker_box",
+            Item::CyanShulkerBox => "block.minecraft.cyan_shulker_box",
+            Item::PurpleShulkerBox => "block.minecraft.purple_shulker_box",
+            Item::BlueShulkerBox => "block.minecraft.blue_shulker_box",
+            Item::BrownShulkerBox => "block.minecraft.brown_shulker_box",
+            Item::GreenShulkerBox => "block.minecraft.green_shulker_box",
+            Item::RedShulkerBox => "block.minecraft.red_shulker_box",
+            Item::BlackShulkerBox => "block.minecraft.black_shulker_box",
+            Item::WhiteGlazedTerracotta => "block.minecraft.white_glazed_terracotta",
+            Item::OrangeGlazedTerracotta => "block.minecraft.orange_glazed_terracotta",
+            Item::MagentaGlazedTerracotta => "block.minecraft.magenta_glazed_terracotta",
+            Item::LightBlueGlazedTerracotta => "block.minecraft.light_blue_glazed_terracotta",
+            Item::YellowGlazedTerracotta => "block.minecraft.yellow_glazed_terracotta",
+            Item::LimeGlazedTerracotta => "block.minecraft.lime_glazed_terracotta",
+            Item::PinkGlazedTerracotta => "block.minecraft.pink_glazed_terracotta",
+            Item::GrayGlazedTerracotta => "block.minecraft.gray_glazed_terracotta",
+            Item::LightGrayGlazedTerracotta => "block.minecraft.light_gray_glazed_terracotta",
+            Item::CyanGlazedTerracotta => "block.minecraft.cyan_glazed_terracotta",
+            Item::PurpleGlazedTerracotta => "block.minecraft.purple_glazed_terracotta",
+            Item::BlueGlazedTerracotta => "block.minecraft.blue_glazed_terracotta",
+            Item::BrownGlazedTerracotta => "block.minecraft.brown_glazed_terracotta",
+            Item::GreenGlazedTerracotta => "block.minecraft.green_glazed_terracotta",
+            Item::RedGlazedTerracotta => "block.minecraft.red_glazed_terracotta",
+            Item::BlackGlazedTerracotta => "block.minecraft.black_glazed_terracotta",
+            Item::WhiteConcrete => "block.minecraft.white_concrete",
+            Item::OrangeConcrete => "block.minecraft.orange_concrete",
+            Item::MagentaConcrete => "block.minecraft.magenta_concrete",
+            Item::LightBlueConcrete => "block.minecraft.light_blue_concrete",
+            Item::YellowConcrete => "block.minecraft.yellow_concrete",
+            Item::LimeConcrete => "block.minecraft.lime_concrete",
+            Item::PinkConcrete => "block.minecraft.pink_concrete",
+            Item::GrayConcrete => "block.minecraft.gray_concrete",
+            Item::LightGrayConcrete => "block.minecraft.light_gray_concrete",
+            Item::CyanConcrete => "block.minecraft.cyan_concrete",
+            Item::PurpleConcrete => "block.minecraft.purple_concrete",
+            Item::BlueConcrete => "block.minecraft.blue_concrete",
+            Item::BrownConcrete => "block.minecraft.brown_concrete",
+            Item::GreenConcrete => "block.minecraft.green_concrete",
+            Item::RedConcrete => "block.minecraft.red_concrete",
+            Item::BlackConcrete => "block.minecraft.black_concrete",
+            Item::WhiteConcretePowder => "block.minecraft.white_concrete_powder",
+            Item::OrangeConcretePowder => "block.minecraft.orange_concrete_powder",
+            Item::MagentaConcretePowder => "block.minecraft.magenta_concrete_powder",
+            Item::LightBlueConcretePowder => "block.minecraft.light_blue_concrete_powder",
+            Item::YellowConcretePowder => "block.minecraft.yellow_concrete_powder",
+            Item::LimeConcretePowder => "block.minecraft.lime_concrete_powder",
+            Item::PinkConcretePowder => "block.minecraft.pink_concrete_powder",
+            Item::GrayConcretePowder => "block.minecraft.gray_concrete_powder",
+            Item::LightGrayConcretePowder => "block.minecraft.light_gray_concrete_powder",
+            Item::CyanConcretePowder => "block.minecraft.cyan_concrete_powder",
+            Item::PurpleConcretePowder => "block.minecraft.purple_concrete_powder",
+            Item::BlueConcretePowder => "block.minecraft.blue_concrete_powder",
+            Item::BrownConcretePowder => "block.minecraft.brown_concrete_powder",
+            Item::GreenConcretePowder => "block.minecraft.green_concrete_powder",
+            Item::RedConcretePowder => "block.minecraft.red_concrete_powder",
+            Item::BlackConcretePowder => "block.minecraft.black_concrete_powder",
+            Item::TurtleEgg => "block.minecraft.turtle_egg",
+            Item::DeadTubeCoralBlock => "block.minecraft.dead_tube_coral_block",
+            Item::DeadBrainCoralBlock => "block.minecraft.dead_brain_coral_block",
+            Item::DeadBubbleCoralBlock => "block.minecraft.dead_bubble_coral_block",
+            Item::DeadFireCoralBlock => "block.minecraft.dead_fire_coral_block",
+            Item::DeadHornCoralBlock => "block.minecraft.dead_horn_coral_block",
+            Item::TubeCoralBlock => "block.minecraft.tube_coral_block",
+            Item::BrainCoralBlock => "block.minecraft.brain_coral_block",
+            Item::BubbleCoralBlock => "block.minecraft.bubble_coral_block",
+            Item::FireCoralBlock => "block.minecraft.fire_coral_block",
+            Item::HornCoralBlock => "block.minecraft.horn_coral_block",
+            Item::TubeCoral => "block.minecraft.tube_coral",
+            Item::BrainCoral => "block.minecraft.brain_coral",
+            Item::BubbleCoral => "block.minecraft.bubble_coral",
+            Item::FireCoral => "block.minecraft.fire_coral",
+            Item::HornCoral => "block.minecraft.horn_coral",
+            Item::DeadBrainCoral => "block.minecraft.dead_brain_coral",
+            Item::DeadBubbleCoral => "block.minecraft.dead_bubble_coral",
+            Item::DeadFireCoral => "block.minecraft.dead_fire_coral",
+            Item::DeadHornCoral => "block.minecraft.dead_horn_coral",
+            Item::DeadTubeCoral => "block.minecraft.dead_tube_coral",
+            Item::TubeCoralFan => "block.minecraft.tube_coral_fan",
+            Item::BrainCoralFan => "block.minecraft.brain_coral_fan",
+            Item::BubbleCoralFan => "block.minecraft.bubble_coral_fan",
+            Item::FireCoralFan => "block.minecraft.fire_coral_fan",
+            Item::HornCoralFan => "block.minecraft.horn_coral_fan",
+            Item::DeadTubeCoralFan => "block.minecraft.dead_tube_coral_fan",
+            Item::DeadBrainCoralFan => "block.minecraft.dead_brain_coral_fan",
+            Item::DeadBubbleCoralFan => "block.minecraft.dead_bubble_coral_fan",
+            Item::DeadFireCoralFan => "block.minecraft.dead_fire_coral_fan",
+            Item::DeadHornCoralFan => "block.minecraft.dead_horn_coral_fan",
+            Item::BlueIce => "block.minecraft.blue_ice",
+            Item::Conduit => "block.minecraft.conduit",
+            Item::IronDoor => "block.minecraft.iron_door",
+            Item::OakDoor => "block.minecraft.oak_door",
+            Item::SpruceDoor => "block.minecraft.spruce_door",
+            Item::BirchDoor => "block.minecraft.birch_door",
+            Item::JungleDoor => "block.minecraft.jungle_door",
+            Item::AcaciaDoor => "block.minecraft.acacia_door",
+            Item::DarkOakDoor => "block.minecraft.dark_oak_door",
+            Item::Repeater => "block.minecraft.repeater",
+            Item::Comparator => "block.minecraft.comparator",
+            Item::StructureBlock => "block.minecraft.structure_block",
+            Item::TurtleHelmet => "item.minecraft.turtle_helmet",
+            Item::Scute => "item.minecraft.scute",
+            Item::IronShovel => "item.minecraft.iron_shovel",
+            Item::IronPickaxe => "item.minecraft.iron_pickaxe",
+            Item::IronAxe => "item.minecraft.iron_axe",
+            Item::FlintAndSteel => "item.minecraft.flint_and_steel",
+            Item::Apple => "item.minecraft.apple",
+            Item::Bow => "item.minecraft.bow",
+            Item::Arrow => "item.minecraft.arrow",
+            Item::Coal => "item.minecraft.coal",
+            Item::Charcoal => "item.minecraft.charcoal",
+            Item::Diamond => "item.minecraft.diamond",
+            Item::IronIngot => "item.minecraft.iron_ingot",
+            Item::GoldIngot => "item.minecraft.gold_ingot",
+            Item::IronSword => "item.minecraft.iron_sword",
+            Item::WoodenSword => "item.minecraft.wooden_sword",
+            Item::WoodenShovel => "item.minecraft.wooden_shovel",
+            Item::WoodenPickaxe => "item.minecraft.wooden_pickaxe",
+            Item::WoodenAxe => "item.minecraft.wooden_axe",
+            Item::StoneSword => "item.minecraft.stone_sword",
+            Item::StoneShovel => "item.minecraft.stone_shovel",
+            Item::StonePickaxe => "item.minecraft.stone_pickaxe",
+            Item::StoneAxe => "item.minecraft.stone_axe",
+            Item::DiamondSword => "item.minecraft.diamond_sword",
+            Item::DiamondShovel => "item.minecraft.diamond_shovel",
+            Item::DiamondPickaxe => "item.minecraft.diamond_pickaxe",
+            Item::DiamondAxe => "item.minecraft.diamond_axe",
+            Item::Stick => "item.minecraft.stick",
+            Item::Bowl => "item.minecraft.bowl",
+            Item::MushroomStew => "item.minecraft.mushroom_stew",
+            Item::GoldenSword => "item.minecraft.golden_sword",
+            Item::GoldenShovel => "item.minecraft.golden_shovel",
+            Item::GoldenPickaxe => "item.minecraft.golden_pickaxe",
+            Item::GoldenAxe => "item.minecraft.golden_axe",
+            Item::String => "item.minecraft.string",
+            Item::Feather => "item.minecraft.feather",
+            Item::Gunpowder => "item.minecraft.gunpowder",
+            Item::WoodenHoe => "item.minecraft.wooden_hoe",
+            Item::StoneHoe => "item.minecraft.stone_hoe",
+            Item::IronHoe => "item.minecraft.iron_hoe",
+            Item::DiamondHoe => "item.minecraft.diamond_hoe",
+            Item::GoldenHoe => "item.minecraft.golden_hoe",
+            Item::WheatSeeds => "item.minecraft.wheat_seeds",
+            Item::Wheat => "block.minecraft.wheat",
+            Item::Bread => "item.minecraft.bread",
+            Item::LeatherHelmet => "item.minecraft.leather_helmet",
+            Item::LeatherChestplate => "item.minecraft.leather_chestplate",
+            Item::LeatherLeggings => "item.minecraft.leather_leggings",
+            Item::LeatherBoots => "item.minecraft.leather_boots",
+            Item::ChainmailHelmet => "item.minecraft.chainmail_helmet",
+            Item::ChainmailChestplate => "item.minecraft.chainmail_chestplate",
+            Item::ChainmailLeggings => "item.minecraft.chainmail_leggings",
+            Item::ChainmailBoots => "item.minecraft.chainmail_boots",
+            Item::IronHelmet => "item.minecraft.iron_helmet",
+            Item::IronChestplate => "item.minecraft.iron_chestplate",
+            Item::IronLeggings => "item.minecraft.iron_leggings",
+            Item::IronBoots => "item.minecraft.iron_boots",
+            Item::DiamondHelmet => "item.minecraft.diamond_helmet",
+            Item::DiamondChestplate => "item.minecraft.diamond_chestplate",
+            Item::DiamondLeggings => "item.minecraft.diamond_leggings",
+            Item::DiamondBoots => "item.minecraft.diamond_boots",
+            Item::GoldenHelmet => "item.minecraft.golden_helmet",
+            Item::GoldenChestplate => "item.minecraft.golden_chestplate",
+            Item::GoldenLeggings => "item.minecraft.golden_leggings",
+            Item::GoldenBoots => "item.minecraft.golden_boots",
+            Item::Flint => "item.minecraft.flint",
+            Item::Porkchop => "item.minecraft.porkchop",
+            Item::CookedPorkchop => "item.minecraft.cooked_porkchop",
+            Item::Painting => "item.minecraft.painting",
+            Item::GoldenApple => "item.minecraft.golden_apple",
+            Item::EnchantedGoldenApple => "item.minecraft.enchanted_golden_apple",
+            Item::Sign => "block.minecraft.sign",
+            Item::Bucket => "item.minecraft.bucket",
+            Item::WaterBucket => "item.minecraft.water_bucket",
+            Item::LavaBucket => "item.minecraft.lava_bucket",
+            Item::Minecart => "item.minecraft.minecart",
+            Item::Saddle => "item.minecraft.saddle",
+            Item::Redstone => "item.minecraft.redstone",
+            Item::Snowball => "item.minecraft.snowball",
+            Item::OakBoat => "item.minecraft.oak_boat",
+            Item::Leather => "item.minecraft.leather",
+            Item::MilkBucket => "item.minecraft.milk_bucket",
+            Item::PufferfishBucket => "item.minecraft.pufferfish_bucket",
+            Item::SalmonBucket => "item.minecraft.salmon_bucket",
+            Item::CodBucket => "item.minecraft.cod_bucket",
+            Item::TropicalFishBucket => "item.minecraft.tropical_fish_bucket",
+            Item::Brick => "item.minecraft.brick",
+            Item::ClayBall => "item.minecraft.clay_ball",
+            Item::SugarCane => "block.minecraft.sugar_cane",
+            Item::Kelp => "block.minecraft.kelp",
+            Item::DriedKelpBlock => "block.minecraft.dried_kelp_block",
+            Item::Paper => "item.minecraft.paper",
+            Item::Book => "item.minecraft.book",
+            Item::SlimeBall => "item.minecraft.slime_ball",
+            Item::ChestMinecart => "item.minecraft.chest_minecart",
+            Item::FurnaceMinecart => "item.minecraft.furnace_minecart",
+            Item::Egg => "item.minecraft.egg",
+            Item::Compass => "item.minecraft.compass",
+            Item::FishingRod => "item.minecraft.fishing_rod",
+            Item::Clock => "item.minecraft.clock",
+            Item::GlowstoneDust => "item.minecraft.glowstone_dust",
+            Item::Cod => "item.minecraft.cod",
+            Item::Salmon => "item.minecraft.salmon",
+            Item::TropicalFish => "item.minecraft.tropical_fish",
+            Item::Pufferfish => "item.minecraft.pufferfish",
+            Item::CookedCod => "item.minecraft.cooked_cod",
+            Item::CookedSalmon => "item.minecraft.cooked_salmon",
+            Item::InkSac => "item.minecraft.ink_sac",
+            Item::RoseRed => "item.minecraft.rose_red",
+            Item::CactusGreen => "item.minecraft.cactus_green",
+            Item::CocoaBeans => "item.minecraft.cocoa_beans",
+            Item::LapisLazuli => "item.minecraft.lapis_lazuli",
+            Item::PurpleDye => "item.minecraft.purple_dye",
+            Item::CyanDye => "item.minecraft.cyan_dye",
+            Item::LightGrayDye => "item.minecraft.light_gray_dye",
+            Item::GrayDye => "item.minecraft.gray_dye",
+            Item::PinkDye => "item.minecraft.pink_dye",
+            Item::LimeDye => "item.minecraft.lime_dye",
+            Item::DandelionYellow => "item.minecraft.dandelion_yellow",
+            Item::LightBlueDye => "item.minecraft.light_blue_dye",
+            Item::MagentaDye => "item.minecraft.magenta_dye",
+            Item::OrangeDye => "item.minecraft.orange_dye",
+            Item::BoneMeal => "item.minecraft.bone_meal",
+            Item::Bone => "item.minecraft.bone",
+            Item::Sugar => "item.minecraft.sugar",
+            Item::Cake => "block.minecraft.cake",
+            Item::WhiteBed => "block.minecraft.white_bed",
+            Item::OrangeBed => "block.minecraft.orange_bed",
+            Item::MagentaBed => "block.minecraft.magenta_bed",
+            Item::LightBlueBed => "block.minecraft.light_blue_bed",
+            Item::YellowBed => "block.minecraft.yellow_bed",
+            Item::LimeBed => "block.minecraft.lime_bed",
+            Item::PinkBed => "block.minecraft.pink_bed",
+            Item::GrayBed => "block.minecraft.gray_bed",
+            Item::LightGrayBed => "block.minecraft.light_gray_bed",
+            Item::CyanBed => "block.minecraft.cyan_bed",
+            Item::PurpleBed => "block.minecraft.purple_bed",
+            Item::BlueBed => "block.minecraft.blue_bed",
+            Item::BrownBed => "block.minecraft.brown_bed",
+            Item::GreenBed => "block.minecraft.green_bed",
+            Item::RedBed => "block.minecraft.red_bed",
+            Item::BlackBed => "block.minecraft.black_bed",
+            Item::Cookie => "item.minecraft.cookie",
+            Item::FilledMap => "item.minecraft.filled_map",
+            Item::Shears => "item.minecraft.shears",
+            Item::MelonSlice => "item.minecraft.melon_slice",
+            Item::DriedKelp => "item.minecraft.dried_kelp",
+            Item::PumpkinSeeds => "item.minecraft.pumpkin_seeds",
+            Item::MelonSeeds => "item.minecraft.melon_seeds",
+            Item::Beef => "item.minecraft.beef",
+            Item::CookedBeef => "item.minecraft.cooked_beef",
+            Item::Chicken => "item.minecraft.chicken",
+            Item::CookedChicken => "item.minecraft.cooked_chicken",
+            Item::RottenFlesh => "item.minecraft.rotten_flesh",
+            Item::EnderPearl => "item.minecraft.ender_pearl",
+            Item::BlazeRod => "item.minecraft.blaze_rod",
+            Item::GhastTear => "item.minecraft.ghast_tear",
+            Item::GoldNugget => "item.minecraft.gold_nugget",
+            Item::NetherWart => "block.minecraft.nether_wart",
+            Item::Potion => "item.minecraft.potion",
+            Item::GlassBottle => "item.minecraft.glass_bottle",
+            Item::SpiderEye => "item.minecraft.spider_eye",
+            Item::FermentedSpiderEye => "item.minecraft.fermented_spider_eye",
+            Item::BlazePowder => "item.minecraft.blaze_powder",
+            Item::MagmaCream => "item.minecraft.magma_cream",
+            Item::BrewingStand => "block.minecraft.brewing_stand",
+            Item::Cauldron => "block.minecraft.cauldron",
+            Item::EnderEye => "item.minecraft.ender_eye",
+            Item::GlisteringMelonSlice => "item.minecraft.glistering_melon_slice",
+            Item::BatSpawnEgg => "item.minecraft.bat_spawn_egg",
+            Item::BlazeSpawnEgg => "item.minecraft.blaze_spawn_egg",
+            Item::CaveSpiderSpawnEgg => "item.minecraft.cave_spider_spawn_egg",
+            Item::ChickenSpawnEgg => "item.minecraft.chicken_spawn_egg",
+            Item::CodSpawnEgg => "item.minecraft.cod_spawn_egg",
+            Item::CowSpawnEgg => "item.minecraft.cow_spawn_egg",
+            Item::CreeperSpawnEgg => "item.minecraft.creeper_spawn_egg",
+            Item::DolphinSpawnEgg => "item.minecraft.dolphin_spawn_egg",
+            Item::DonkeySpawnEgg => "item.minecraft.donkey_spawn_egg",
+            Item::DrownedSpawnEgg => "item.minecraft.drowned_spawn_egg",
+            Item::ElderGuardianSpawnEgg => "item.minecraft.elder_guardian_spawn_egg",
+            Item::EndermanSpawnEgg => "item.minecraft.enderman_spawn_egg",
+            Item::EndermiteSpawnEgg => "item.minecraft.endermite_spawn_egg",
+            Item::EvokerSpawnEgg => "item.minecraft.evoker_spawn_egg",
+            Item::GhastSpawnEgg => "item.minecraft.ghast_spawn_egg",
+            Item::GuardianSpawnEgg => "item.minecraft.guardian_spawn_egg",
+            Item::HorseSpawnEgg => "item.minecraft.horse_spawn_egg",
+            Item::HuskSpawnEgg => "item.minecraft.husk_spawn_egg",
+            Item::LlamaSpawnEgg => "item.minecraft.llama_spawn_egg",
+            Item::MagmaCubeSpawnEgg => "item.minecraft.magma_cube_spawn_egg",
+            Item::MooshroomSpawnEgg => "item.minecraft.mooshroom_spawn_egg",
+            Item::MuleSpawnEgg => "item.minecraft.mule_spawn_egg",
+            Item::OcelotSpawnEgg => "item.minecraft.ocelot_spawn_egg",
+            Item::ParrotSpawnEgg => "item.minecraft.parrot_spawn_egg",
+            Item::PhantomSpawnEgg => "item.minecraft.phantom_spawn_egg",
+            Item::PigSpawnEgg => "item.minecraft.pig_spawn_egg",
+            Item::PolarBearSpawnEgg => "item.minecraft.polar_bear_spawn_egg",
+            Item::PufferfishSpawnEgg => "item.minecraft.pufferfish_spawn_egg",
+            Item::RabbitSpawnEgg => "item.minecraft.rabbit_spawn_egg",
+            Item::SalmonSpawnEgg => "item.minecraft.salmon_spawn_egg",
+            Item::SheepSpawnEgg => "item.minecraft.sheep_spawn_egg",
+            Item::ShulkerSpawnEgg => "item.minecraft.shulker_spawn_egg",
+            Item::SilverfishSpawnEgg => "item.minecraft.silverfish_spawn_egg",
+            Item::SkeletonSpawnEgg => "item.minecraft.skeleton_spawn_egg",
+            Item::SkeletonHorseSpawnEgg => "item.minecraft.skeleton_horse_spawn_egg",
+            Item::SlimeSpawnEgg => "item.minecraft.slime_spawn_egg",
+            Item::SpiderSpawnEgg => "item.minecraft.spider_spawn_egg",
+            Item::SquidSpawnEgg => "item.minecraft.squid_spawn_egg",
+            Item::StraySpawnEgg => "item.minecraft.stray_spawn_egg",
+            Item::TropicalFishSpawnEgg => "item.minecraft.tropical_fish_spawn_egg",
+            Item::TurtleSpawnEgg => "item.minecraft.turtle_spawn_egg",
+            Item::VexSpawnEgg => "item.minecraft.vex_spawn_egg",
+            Item::VillagerSpawnEgg => "item.minecraft.villager_spawn_egg",
+            Item::VindicatorSpawnEgg => "item.minecraft.vindicator_spawn_egg",
+            Item::WitchSpawnEgg => "item.minecraft.witch_spawn_egg",
+            Item::WitherSkeletonSpawnEgg => "item.minecraft.wither_skeleton_spawn_egg",
+            Item::WolfSpawnEgg => "item.minecraft.wolf_spawn_egg",
+            Item::ZombieSpawnEgg => "item.minecraft.zombie_spawn_egg",
+            Item::ZombieHorseSpawnEgg => "item.minecraft.zombie_horse_spawn_egg",
+            Item::ZombiePigmanSpawnEgg => "item.minecraft.zombie_pigman_spawn_egg",
+            Item::ZombieVillagerSpawnEgg => "item.minecraft.zombie_villager_spawn_egg",
+            Item::ExperienceBottle => "item.minecraft.experience_bottle",
+            Item::FireCharge => "item.minecraft.fire_charge",
+            Item::WritableBook => "item.minecraft.writable_book",
+            Item::WrittenBook => "item.minecraft.written_book",
+            Item::Emerald => "item.minecraft.emerald",
+            Item::ItemFrame => "item.minecraft.item_frame",
+            Item::FlowerPot => "block.minecraft.flower_pot",
+            Item::Carrot => "item.minecraft.carrot",
+            Item::Potato => "item.minecraft.potato",
+            Item::BakedPotato => "item.minecraft.baked_potato",
+            Item::PoisonousPotato => "item.minecraft.poisonous_potato",
+            Item::Map => "item.minecraft.map",
+            Item::GoldenCarrot => "item.minecraft.golden_carrot",
+            Item::SkeletonSkull => "block.minecraft.skeleton_skull",
+            Item::WitherSkeletonSkull => "block.minecraft.wither_skeleton_skull",
+            Item::PlayerHead => "block.minecraft.player_head",
+            Item::ZombieHead => "block.minecraft.zombie_head",
+            Item::CreeperHead => "block.minecraft.creeper_head",
+            Item::DragonHead => "block.minecraft.dragon_head",
+            Item::CarrotOnAStick => "item.minecraft.carrot_on_a_stick",
+            Item::NetherStar => "item.minecraft.nether_star",
+            Item::PumpkinPie => "item.minecraft.pumpkin_pie",
+            Item::FireworkRocket => "item.minecraft.firework_rocket",
+            Item::FireworkStar => "item.minecraft.firework_star",
+            Item::EnchantedBook => "item.minecraft.enchanted_book",
+            Item::NetherBrick => "item.minecraft.nether_brick",
+            Item::Quartz => "item.minecraft.quartz",
+            Item::TntMinecart => "item.minecraft.tnt_minecart",
+            Item::HopperMinecart => "item.minecraft.hopper_minecart",
+            Item::PrismarineShard => "item.minecraft.prismarine_shard",
+            Item::PrismarineCrystals => "item.minecraft.prismarine_crystals",
+            Item::Rabbit => "item.minecraft.rabbit",
+            Item::CookedRabbit => "item.minecraft.cooked_rabbit",
+            Item::RabbitStew => "item.minecraft.rabbit_stew",
+            Item::RabbitFoot => "item.minecraft.rabbit_foot",
+            Item::RabbitHide => "item.minecraft.rabbit_hide",
+            Item::ArmorStand => "item.minecraft.armor_stand",
+            Item::IronHorseArmor => "item.minecraft.iron_horse_armor",
+            Item::GoldenHorseArmor => "item.minecraft.golden_horse_armor",
+            Item::DiamondHorseArmor => "item.minecraft.diamond_horse_armor",
+            Item::Lead => "item.minecraft.lead",
+            Item::NameTag => "item.minecraft.name_tag",
+            Item::CommandBlockMinecart => "item.minecraft.command_block_minecart",
+            Item::Mutton => "item.minecraft.mutton",
+            Item::CookedMutton => "item.minecraft.cooked_mutton",
+            Item::WhiteBanner => "block.minecraft.white_banner",
+            Item::OrangeBanner => "block.minecraft.orange_banner",
+            Item::MagentaBanner => "block.minecraft.magenta_banner",
+            Item::LightBlueBanner => "block.minecraft.light_blue_banner",
+            Item::YellowBanner => "block.minecraft.yellow_banner",
+            Item::LimeBanner => "block.minecraft.lime_banner",
+            Item::PinkBanner => "block.minecraft.pink_banner",
+            Item::GrayBanner => "block.minecraft.gray_banner",
+            Item::LightGrayBanner => "block.minecraft.light_gray_banner",
+            Item::CyanBanner => "block.minecraft.cyan_banner",
+            Item::PurpleBanner => "block.minecraft.purple_banner",
+            Item::BlueBanner => "block.minecraft.blue_banner",
+            Item::BrownBanner => "block.minecraft.brown_banner",
+            Item::GreenBanner => "block.minecraft.green_banner",
+            Item::RedBanner => "block.minecraft.red_banner",
+            Item::BlackBanner => "block.minecraft.black_banner",
+            Item::EndCrystal => "item.minecraft.end_crystal",
+            Item::ChorusFruit => "item.minecraft.chorus_fruit",
+            Item::PoppedChorusFruit => "item.minecraft.popped_chorus_fruit",
+            Item::Beetroot => "item.minecraft.beetroot",
+            Item::BeetrootSeeds => "item.minecraft.beetroot_seeds",
+            Item::BeetrootSoup => "item.minecraft.beetroot_soup",
+            Item::DragonBreath => "item.minecraft.dragon_breath",
+            Item::SplashPotion => "item.minecraft.splash_potion",
+            Item::SpectralArrow => "item.minecraft.spectral_arrow",
+            Item::TippedArrow => "item.minecraft.tipped_arrow",
+            Item::LingeringPotion => "item.minecraft.lingering_potion",
+            Item::Shield => "item.minecraft.shield",
+            Item::Elytra => "item.minecraft.elytra",
+            Item::SpruceBoat => "item.minecraft.spruce_boat",
+            Item::BirchBoat => "item.minecraft.birch_boat",
+            Item::JungleBoat => "item.minecraft.jungle_boat",
+            Item::AcaciaBoat => "item.minecraft.acacia_boat",
+            Item::DarkOakBoat => "item.minecraft.dark_oak_boat",
+            Item::TotemOfUndying => "item.minecraft.totem_of_undying",
+            Item::ShulkerShell => "item.minecraft.shulker_shell",
+            Item::IronNugget => "item.minecraft.iron_nugget",
+            Item::KnowledgeBook => "item.minecraft.knowledge_book",
+            Item::DebugStick => "item.minecraft.debug_stick",
+            Item::MusicDisc13 => "item.minecraft.music_disc_13",
+            Item::MusicDiscCat => "item.minecraft.music_disc_cat",
+            Item::MusicDiscBlocks => "item.minecraft.music_disc_blocks",
+            Item::MusicDiscChirp => "item.minecraft.music_disc_chirp",
+            Item::MusicDiscFar => "item.minecraft.music_disc_far",
+            Item::MusicDiscMall => "item.minecraft.music_disc_mall",
+            Item::MusicDiscMellohi => "item.minecraft.music_disc_mellohi",
+            Item::MusicDiscStal => "item.minecraft.music_disc_stal",
+            Item::MusicDiscStrad => "item.minecraft.music_disc_strad",
+            Item::MusicDiscWard => "item.minecraft.music_disc_ward",
+            Item::MusicDisc11 => "item.minecraft.music_disc_11",
+            Item::MusicDiscWait => "item.minecraft.music_disc_wait",
+            Item::Trident => "item.minecraft.trident",
+            Item::PhantomMembrane => "item.minecraft.phantom_membrane",
+            Item::NautilusShell => "item.minecraft.nautilus_shell",
+            Item::HeartOfTheSea => "item.minecraft.heart_of_the_sea",
+        }
+    }
+
+    pub const ALL: [Item; 790] = [
+        Item::Air,
+        Item::Stone,
+        Item::Granite,
+        Item::PolishedGranite,
+        Item::Diorite,
+        Item::PolishedDiorite,
+        Item::Andesite,
+        Item::PolishedAndesite,
+        Item::GrassBlock,
+        Item::Dirt,
+        Item::CoarseDirt,
+        Item::Podzol,
+        Item::Cobblestone,
+        Item::OakPlanks,
+        Item::SprucePlanks,
+        Item::BirchPlanks,
+        Item::JunglePlanks,
+        Item::AcaciaPlanks,
+        Item::DarkOakPlanks,
+        Item::OakSapling,
+        Item::SpruceSapling,
+        Item::BirchSapling,
+        Item::JungleSapling,
+        Item::AcaciaSapling,
+        Item::DarkOakSapling,
+        Item::Bedrock,
+        Item::Sand,
+        Item::RedSand,
+        Item::Gravel,
+        Item::GoldOre,
+        Item::IronOre,
+        Item::CoalOre,
+        Item::OakLog,
+        Item::SpruceLog,
+        Item::BirchLog,
+        Item::JungleLog,
+        Item::AcaciaLog,
+        Item::DarkOakLog,
+        Item::StrippedOakLog,
+        Item::StrippedSpruceLog,
+        Item::StrippedBirchLog,
+        Item::StrippedJungleLog,
+        Item::StrippedAcaciaLog,
+        Item::StrippedDarkOakLog,
+        Item::StrippedOakWood,
+        Item::StrippedSpruceWood,
+        Item::StrippedBirchWood,
+        Item::StrippedJungleWood,
+        Item::StrippedAcaciaWood,
+        Item::StrippedDarkOakWood,
+        Item::OakWood,
+        Item::SpruceWood,
+        Item::BirchWood,
+        Item::JungleWood,
+        Item::AcaciaWood,
+        Item::DarkOakWood,
+        Item::OakLeaves,
+        Item::SpruceLeaves,
+        Item::BirchLeaves,
+        Item::JungleLeaves,
+        Item::AcaciaLeaves,
+        Item::DarkOakLeaves,
+        Item::Sponge,
+        Item::WetSponge,
+        Item::Glass,
+        Item::LapisOre,
+        Item::LapisBlock,
+        Item::Dispenser,
+        Item::Sandstone,
+        Item::ChiseledSandstone,
+        Item::CutSandstone,
+        Item::NoteBlock,
+        Item::PoweredRail,
+        Item::DetectorRail,
+        Item::StickyPiston,
+        Item::Cobweb,
+        Item::Grass,
+        Item::Fern,
+        Item::DeadBush,
+        Item::Seagrass,
+        Item::SeaPickle,
+        Item::Piston,
+        Item::WhiteWool,
+        Item::OrangeWool,
+        Item::MagentaWool,
+        Item::LightBlueWool,
+        Item::YellowWool,
+        Item::LimeWool,
+        Item::PinkWool,
+        Item::GrayWool,
+        Item::LightGrayWool,
+        Item::CyanWool,
+        Item::PurpleWool,
+        Item::BlueWool,
+        Item::BrownWool,
+        Item::GreenWool,
+        Item::RedWool,
+        Item::BlackWool,
+        Item::Dandelion,
+        Item::Poppy,
+        Item::BlueOrchid,
+        Item::Allium,
+        Item::AzureBluet,
+        Item::RedTulip,
+        Item::OrangeTulip,
+        Item::WhiteTulip,
+        Item::PinkTulip,
+        Item::OxeyeDaisy,
+        Item::BrownMushroom,
+        Item::RedMushroom,
+        Item::GoldBlock,
+        Item::IronBlock,
+        Item::OakSlab,
+        Item::SpruceSlab,
+        Item::BirchSlab,
+        Item::JungleSlab,
+        Item::AcaciaSlab,
+        Item::DarkOakSlab,
+        Item::StoneSlab,
+        Item::SandstoneSlab,
+        Item::PetrifiedOakSlab,
+        Item::CobblestoneSlab,
+        Item::BrickSlab,
+        Item::StoneBrickSlab,
+        Item::NetherBrickSlab,
+        Item::QuartzSlab,
+        Item::RedSandstoneSlab,
+        Item::PurpurSlab,
+        Item::PrismarineSlab,
+        Item::PrismarineBrickSlab,
+        Item::DarkPrismarineSlab,
+        Item::SmoothQuartz,
+        Item::SmoothRedSandstone,
+        Item::SmoothSandstone,
+        Item::SmoothStone,
+        Item::Bricks,
+        Item::Tnt,
+        Item::Bookshelf,
+        Item::MossyCobblestone,
+        Item::Obsidian,
+        Item::Torch,
+        Item::EndRod,
+        Item::ChorusPlant,
+        Item::ChorusFlower,
+        Item::PurpurBlock,
+        Item::PurpurPillar,
+        Item::PurpurStairs,
+        Item::Spawner,
+        Item::OakStairs,
+        Item::Chest,
+        Item::DiamondOre,
+        Item::DiamondBlock,
+        Item::CraftingTable,
+        Item::Farmland,
+        Item::Furnace,
+        Item::Ladder,
+        Item::Rail,
+        Item::CobblestoneStairs,
+        Item::Lever,
+        Item::StonePressurePlate,
+        Item::OakPressurePlate,
+        Item::SprucePressurePlate,
+        Item::BirchPressurePlate,
+        Item::JunglePressurePlate,
+        Item::AcaciaPressurePlate,
+        Item::DarkOakPressurePlate,
+        Item::RedstoneOre,
+        Item::RedstoneTorch,
+        Item::StoneButton,
+        Item::Snow,
+        Item::Ice,
+        Item::SnowBlock,
+        Item::Cactus,
+        Item::Clay,
+        Item::Jukebox,
+        Item::OakFence,
+        Item::SpruceFence,
+        Item::BirchFence,
+        Item::JungleFence,
+        Item::AcaciaFence,
+        Item::DarkOakFence,
+        Item::Pumpkin,
+        Item::CarvedPumpkin,
+        Item::Netherrack,
+        Item::SoulSand,
+        Item::Glowstone,
+        Item::JackOLantern,
+        Item::OakTrapdoor,
+        Item::SpruceTrapdoor,
+        Item::BirchTrapdoor,
+        Item::JungleTrapdoor,
+        Item::AcaciaTrapdoor,
+        Item::DarkOakTrapdoor,
+        Item::InfestedStone,
+        Item::InfestedCobblestone,
+        Item::InfestedStoneBricks,
+        Item::InfestedMossyStoneBricks,
+        Item::InfestedCrackedStoneBricks,
+        Item::InfestedChiseledStoneBricks,
+        Item::StoneBricks,
+        Item::MossyStoneBricks,
+        Item::CrackedStoneBricks,
+        Item::ChiseledStoneBricks,
+        Item::BrownMushroomBlock,
+        Item::RedMushroomBlock,
+        Item::MushroomStem,
+        Item::IronBars,
+        Item::GlassPane,
+        Item::Melon,
+        Item::Vine,
+        Item::OakFenceGate,
+        Item::SpruceFenceGate,
+        Item::BirchFenceGate,
+        Item::JungleFenceGate,
+        Item::AcaciaFenceGate,
+        Item::DarkOakFenceGate,
+        Item::BrickStairs,
+        Item::StoneBrickStairs,
+        Item::Mycelium,
+        Item::LilyPad,
+        Item::NetherBricks,
+        Item::NetherBrickFence,
+        Item::NetherBrickStairs,
+        Item::EnchantingTable,
+        Item::EndPortalFrame,
+        Item::EndStone,
+        Item::EndStoneBricks,
+        Item::DragonEgg,
+        Item::RedstoneLamp,
+        Item::SandstoneStairs,
+        Item::EmeraldOre,
+        Item::EnderChest,
+        Item::TripwireHook,
+        Item::EmeraldBlock,
+        Item::SpruceStairs,
+        Item::BirchStairs,
+        Item::JungleStairs,
+        Item::CommandBlock,
+        Item::Beacon,
+        Item::CobblestoneWall,
+        Item::MossyCobblestoneWall,
+        Item::OakButton,
+        Item::SpruceButton,
+        Item::BirchButton,
+        Item::JungleButton,
+        Item::AcaciaButton,
+        Item::DarkOakButton,
+        Item::Anvil,
+        Item::ChippedAnvil,
+        Item::DamagedAnvil,
+        Item::TrappedChest,
+        Item::LightWeightedPressurePlate,
+        Item::HeavyWeightedPressurePlate,
+        Item::DaylightDetector,
+        Item::RedstoneBlock,
+        Item::NetherQuartzOre,
+        Item::Hopper,
+        Item::ChiseledQuartzBlock,
+        Item::QuartzBlock,
+        Item::QuartzPillar,
+        Item::QuartzStairs,
+        Item::ActivatorRail,
+        Item::Dropper,
+        Item::WhiteTerracotta,
+        Item::OrangeTerracotta,
+        Item::MagentaTerracotta,
+        Item::LightBlueTerracotta,
+        Item::YellowTerracotta,
+        Item::LimeTerracotta,
+        Item::PinkTerracotta,
+        Item::GrayTerracotta,
+        Item::LightGrayTerracotta,
+        Item::CyanTerracotta,
+        Item::PurpleTerracotta,
+        Item::BlueTerracotta,
+        Item::BrownTerracotta,
+        Item::GreenTerracotta,
+        Item::RedTerracotta,
+        Item::BlackTerracotta,
+        Item::Barrier,
+        Item::IronTrapdoor,
+        Item::HayBlock,
+        Item::WhiteCarpet,
+        Item::OrangeCarpet,
+        Item::MagentaCarpet,
+        Item::LightBlueCarpet,
+        Item::YellowCarpet,
+        Item::LimeCarpet,
+        Item::PinkCarpet,
+        Item::GrayCarpet,
+        Item::LightGrayCarpet,
+        Item::CyanCarpet,
+        Item::PurpleCarpet,
+        Item::BlueCarpet,
+        Item::BrownCarpet,
+        Item::GreenCarpet,
+        Item::RedCarpet,
+        Item::BlackCarpet,
+        Item::Terracotta,
+        Item::CoalBlock,
+        Item::PackedIce,
+        Item::AcaciaStairs,
+        Item::DarkOakStairs,
+        Item::SlimeBlock,
+        Item::GrassPath,
+        Item::Sunflower,
+        Item::Lilac,
+        Item::RoseBush,
+        Item::Peony,
+        Item::TallGrass,
+        Item::LargeFern,
+        Item::WhiteStainedGlass,
+        Item::OrangeStainedGlass,
+        Item::MagentaStainedGlass,
+        Item::LightBlueStainedGlass,
+        Item::YellowStainedGlass,
+        Item::LimeStainedGlass,
+        Item::PinkStainedGlass,
+        Item::GrayStainedGlass,
+        Item::LightGrayStainedGlass,
+        Item::CyanStainedGlass,
+        Item::PurpleStainedGlass,
+        Item::BlueStainedGlass,
+        Item::BrownStainedGlass,
+        Item::GreenStainedGlass,
+        Item::RedStainedGlass,
+        Item::BlackStainedGlass,
+        Item::WhiteStainedGlassPane,
+        Item::OrangeStainedGlassPane,
+        Item::MagentaStainedGlassPane,
+        Item::LightBlueStainedGlassPane,
+        Item::YellowStainedGlassPane,
+        Item::LimeStainedGlassPane,
+        Item::PinkStainedGlassPane,
+        Item::GrayStainedGlassPane,
+        Item::LightGrayStainedGlassPane,
+        Item::CyanStainedGlassPane,
+        Item::PurpleStainedGlassPane,
+        Item::BlueStainedGlassPane,
+        Item::BrownStainedGlassPane,
+        Item::GreenStainedGlassPane,
+        Item::RedStainedGlassPane,
+        Item::BlackStainedGlassPane,
+        Item::Prismarine,
+        Item::PrismarineBricks,
+        Item::DarkPrismarine,
+        Item::PrismarineStairs,
+        Item::PrismarineBrickStairs,
+        Item::DarkPrismarineStairs,
+        Item::SeaLantern,
+        Item::RedSandstone,
+        Item::ChiseledRedSandstone,
+        Item::CutRedSandstone,
+        Item::RedSandstoneStairs,
+        Item::RepeatingCommandBlock,
+        Item::ChainCommandBlock,
+        Item::MagmaBlock,
+        Item::NetherWartBlock,
+        Item::RedNetherBricks,
+        Item::BoneBlock,
+        Item::StructureVoid,
+        Item::Observer,
+        Item::ShulkerBox,
+        Item::WhiteShulkerBox,
+        Item::OrangeShulkerBox,
+        Item::MagentaShulkerBox,
+        Item::LightBlueShulkerBox,
+        Item::YellowShulkerBox,
+        Item::LimeShulkerBox,
+        Item::PinkShulkerBox,
+        Item::GrayShulkerBox,
+        Item::LightGrayShulkerBox,
+        Item::CyanShulkerBox,
+        Item::PurpleShulkerBox,
+        Item::BlueShulkerBox,
+        Item::BrownShulkerBox,
+        Item::GreenShulkerBox,
+        Item::RedShulkerBox,
+        Item::BlackShulkerBox,
+        Item::WhiteGlazedTerracotta,
+        Item::OrangeGlazedTerracotta,
+        Item::MagentaGlazedTerracotta,
+        Item::LightBlueGlazedTerracotta,
+        Item::YellowGlazedTerracotta,
+        Item::LimeGlazedTerracotta,
+        Item::PinkGlazedTerracotta,
+        Item::GrayGlazedTerracotta,
+        Item::LightGrayGlazedTerracotta,
+        Item::CyanGlazedTerracotta,
+        Item::PurpleGlazedTerracotta,
+        Item::BlueGlazedTerracotta,
+        Item::BrownGlazedTerracotta,
+        Item::GreenGlazedTerracotta,
+        Item::RedGlazedTerracotta,
+        Item::BlackGlazedTerracotta,
+        Item::WhiteConcrete,
+        Item::OrangeConcrete,
+        Item::MagentaConcrete,
+        Item::LightBlueConcrete,
+        Item::YellowConcrete,
+        Item::LimeConcrete,
+        Item::PinkConcrete,
+        Item::GrayConcrete,
+        Item::LightGrayConcrete,
+        Item::CyanConcrete,
+        Item::PurpleConcrete,
+        Item::BlueConcrete,
+        Item::BrownConcrete,
+        Item::GreenConcrete,
+        Item::RedConcrete,
+        Item::BlackConcrete,
+        Item::WhiteConcretePowder,
+        Item::OrangeConcretePowder,
+        Item::MagentaConcretePowder,
+        Item::LightBlueConcretePowder,
+        Item::YellowConcretePowder,
+        Item::LimeConcretePowder,
+        Item::PinkConcretePowder,
+        Item::GrayConcretePowder,
+        Item::LightGrayConcretePowder,
+        Item::CyanConcretePowder,
+        Item::PurpleConcretePowder,
+        Item::BlueConcretePowder,
+        Item::BrownConcretePowder,
+        Item::GreenConcretePowder,
+        Item::RedConcretePowder,
+        Item::BlackConcretePowder,
+        Item::TurtleEgg,
+        Item::DeadTubeCoralBlock,
+        Item::DeadBrainCoralBlock,
+        Item::DeadBubbleCoralBlock,
+        Item::DeadFireCoralBlock,
+        Item::DeadHornCoralBlock,
+        Item::TubeCoralBlock,
+        Item::BrainCoralBlock,
+        Item::BubbleCoralBlock,
+        Item::FireCoralBlock,
+        Item::HornCoralBlock,
+        Item::TubeCoral,
+        Item::BrainCoral,
+        Item::BubbleCoral,
+        Item::FireCoral,
+        Item::HornCoral,
+        Item::DeadBrainCoral,
+        Item::DeadBubbleCoral,
+        Item::DeadFireCoral,
+        Item::DeadHornCoral,
+        Item::DeadTubeCoral,
+        Item::TubeCoralFan,
+        Item::BrainCoralFan,
+        Item::BubbleCoralFan,
+        Item::FireCoralFan,
+        Item::HornCoralFan,
+        Item::DeadTubeCoralFan,
+        Item::DeadBrainCoralFan,
+        Item::DeadBubbleCoralFan,
+        Item::DeadFireCoralFan,
+        Item::DeadHornCoralFan,
+        Item::BlueIce,
+        Item::Conduit,
+        Item::IronDoor,
+        Item::OakDoor,
+        Item::SpruceDoor,
+        Item::BirchDoor,
+        Item::JungleDoor,
+        Item::AcaciaDoor,
+        Item::DarkOakDoor,
+        Item::Repeater,
+        Item::Comparator,
+        Item::StructureBlock,
+        Item::TurtleHelmet,
+        Item::Scute,
+        Item::IronShovel,
+        Item::IronPickaxe,
+        Item::IronAxe,
+        Item::FlintAndSteel,
+        Item::Apple,
+        Item::Bow,
+        Item::Arrow,
+        Item::Coal,
+        Item::Charcoal,
+        Item::Diamond,
+        Item::IronIngot,
+        Item::GoldIngot,
+        Item::IronSword,
+        Item::WoodenSword,
+        Item::WoodenShovel,
+        Item::WoodenPickaxe,
+        Item::WoodenAxe,
+        Item::StoneSword,
+        Item::StoneShovel,
+        Item::StonePickaxe,
+        Item::StoneAxe,
+        Item::DiamondSword,
+        Item::DiamondShovel,
+        Item::DiamondPickaxe,
+        Item::DiamondAxe,
+        Item::Stick,
+        Item::Bowl,
+        Item::MushroomStew,
+        Item::GoldenSword,
+        Item::GoldenShovel,
+        Item::GoldenPickaxe,
+        Item::GoldenAxe,
+        Item::String,
+        Item::Feather,
+        Item::Gunpowder,
+        Item::WoodenHoe,
+        Item::StoneHoe,
+        Item::IronHoe,
+        Item::DiamondHoe,
+        Item::GoldenHoe,
+        Item::WheatSeeds,
+        Item::Wheat,
+        Item::Bread,
+        Item::LeatherHelmet,
+        Item::LeatherChestplate,
+        Item::LeatherLeggings,
+        Item::LeatherBoots,
+        Item::ChainmailHelmet,
+        Item::ChainmailChestplate,
+        Item::ChainmailLeggings,
+        Item::ChainmailBoots,
+        Item::IronHelmet,
+        Item::IronChestplate,
+        Item::IronLeggings,
+        Item::IronBoots,
+        Item::DiamondHelmet,
+        Item::DiamondChestplate,
+        Item::DiamondLeggings,
+        Item::DiamondBoots,
+        Item::GoldenHelmet,
+        Item::GoldenChestplate,
+        Item::GoldenLeggings,
+        Item::GoldenBoots,
+        Item::Flint,
+        Item::Porkchop,
+        Item::CookedPorkchop,
+        Item::Painting,
+        Item::GoldenApple,
+        Item::EnchantedGoldenApple,
+        Item::Sign,
+        Item::Bucket,
+        Item::WaterBucket,
+        Item::LavaBucket,
+        Item::Minecart,
+        Item::Saddle,
+        Item::Redstone,
+        Item::Snowball,
+        Item::OakBoat,
+        Item::Leather,
+        Item::MilkBucket,
+        Item::PufferfishBucket,
+        Item::SalmonBucket,
+        Item::CodBucket,
+        Item::TropicalFishBucket,
+        Item::Brick,
+        Item::ClayBall,
+        Item::SugarCane,
+        Item::Kelp,
+        Item::DriedKelpBlock,
+        Item::Paper,
+        Item::Book,
+        Item::SlimeBall,
+        Item::ChestMinecart,
+        Item::FurnaceMinecart,
+        Item::Egg,
+        Item::Compass,
+        Item::FishingRod,
+        Item::Clock,
+        Item::GlowstoneDust,
+        Item::Cod,
+        Item::Salmon,
+        Item::TropicalFish,
+        Item::Pufferfish,
+        Item::CookedCod,
+        Item::CookedSalmon,
+        Item::InkSac,
+        Item::RoseRed,
+        Item::CactusGreen,
+        Item::CocoaBeans,
+        Item::LapisLazuli,
+        Item::PurpleDye,
+        Item::CyanDye,
+        Item::LightGrayDye,
+        Item::GrayDye,
+        Item::PinkDye,
+        Item::LimeDye,
+        Item::DandelionYellow,
+        Item::LightBlueDye,
+        Item::MagentaDye,
+        Item::OrangeDye,
+        Item::BoneMeal,
+        Item::Bone,
+        Item::Sugar,
+        Item::Cake,
+        Item::WhiteBed,
+        Item::OrangeBed,
+        Item::MagentaBed,
+        Item::LightBlueBed,
+        Item::YellowBed,
+        Item::LimeBed,
+        Item::PinkBed,
+        Item::GrayBed,
+        Item::LightGrayBed,
+        Item::CyanBed,
+        Item::PurpleBed,
+        Item::BlueBed,
+        Item::BrownBed,
+        Item::GreenBed,
+        Item::RedBed,
+        Item::BlackBed,
+        Item::Cookie,
+        Item::FilledMap,
+        Item::Shears,
+        Item::MelonSlice,
+        Item::DriedKelp,
+        Item::PumpkinSeeds,
+        Item::MelonSeeds,
+        Item::Beef,
+        Item::CookedBeef,
+        Item::Chicken,
+        Item::CookedChicken,
+        Item::RottenFlesh,
+        Item::EnderPearl,
+        Item::BlazeRod,
+        Item::GhastTear,
+        Item::GoldNugget,
+        Item::NetherWart,
+        Item::Potion,
+        Item::GlassBottle,
+        Item::SpiderEye,
+        Item::FermentedSpiderEye,
+        Item::BlazePowder,
+        Item::MagmaCream,
+        Item::BrewingStand,
+        Item::Cauldron,
+        Item::EnderEye,
+        Item::GlisteringMelonSlice,
+        Item::BatSpawnEgg,
+        Item::BlazeSpawnEgg,
+        Item::CaveSpiderSpawnEgg,
+        Item::ChickenSpawnEgg,
+        Item::CodSpawnEgg,
+        Item::CowSpawnEgg,
+        Item::CreeperSpawnEgg,
+        Item::DolphinSpawnEgg,
+        Item::DonkeySpawnEgg,
+        Item::DrownedSpawnEgg,
+        Item::ElderGuardianSpawnEgg,
+        Item::EndermanSpawnEgg,
+        Item::EndermiteSpawnEgg,
+        Item::EvokerSpawnEgg,
+        Item::GhastSpawnEgg,
+        Item::GuardianSpawnEgg,
+        Item::HorseSpawnEgg,
+        Item::HuskSpawnEgg,
+        Item::LlamaSpawnEgg,
+        Item::MagmaCubeSpawnEgg,
+        Item::MooshroomSpawnEgg,
+        Item::MuleSpawnEgg,
+        Item::OcelotSpawnEgg,
+        Item::ParrotSpawnEgg,
+        Item::PhantomSpawnEgg,
+        Item::PigSpawnEgg,
+        Item::PolarBearSpawnEgg,
+        Item::PufferfishSpawnEgg,
+        Item::RabbitSpawnEgg,
+        Item::SalmonSpawnEgg,
+        Item::SheepSpawnEgg,
+        Item::ShulkerSpawnEgg,
+        Item::SilverfishSpawnEgg,
+        Item::SkeletonSpawnEgg,
+        Item::SkeletonHorseSpawnEgg,
+        Item::SlimeSpawnEgg,
+        Item::SpiderSpawnEgg,
+        Item::SquidSpawnEgg,
+        Item::StraySpawnEgg,
+        Item::TropicalFishSpawnEgg,
+        Item::TurtleSpawnEgg,
+        Item::VexSpawnEgg,
+        Item::VillagerSpawnEgg,
+        Item::VindicatorSpawnEgg,
+        Item::WitchSpawnEgg,
+        Item::WitherSkeletonSpawnEgg,
+        Item::WolfSpawnEgg,
+        Item::ZombieSpawnEgg,
+        Item::ZombieHorseSpawnEgg,
+        Item::ZombiePigmanSpawnEgg,
+        Item::ZombieVillagerSpawnEgg,
+        Item::ExperienceBottle,
+        Item::FireCharge,
+        Item::WritableBook,
+        Item::WrittenBook,
+        Item::Emerald,
+        Item::ItemFrame,
+        Item::FlowerPot,
+        Item::Carrot,
+        Item::Potato,
+        Item::BakedPotato,
+        Item::PoisonousPotato,
+        Item::Map,
+        Item::GoldenCarrot,
+        Item::SkeletonSkull,
+        Item::WitherSkeletonSkull,
+        Item::PlayerHead,
+        Item::ZombieHead,
+        Item::CreeperHead,
+        Item::DragonHead,
+        Item::CarrotOnAStick,
+        Item::NetherStar,
+        Item::PumpkinPie,
+        Item::FireworkRocket,
+        Item::FireworkStar,
+        Item::EnchantedBook,
+        Item::NetherBrick,
+        Item::Quartz,
+        Item::TntMinecart,
+        Item::HopperMinecart,
+        Item::PrismarineShard,
+        Item::PrismarineCrystals,
+        Item::Rabbit,
+        Item::CookedRabbit,
+        Item::RabbitStew,
+        Item::RabbitFoot,
+        Item::RabbitHide,
+        Item::ArmorStand,
+        Item::IronHorseArmor,
+        Item::GoldenHorseArmor,
+        Item::DiamondHorseArmor,
+        Item::Lead,
+        Item::NameTag,
+        Item::CommandBlockMinecart,
+        Item::Mutton,
+        Item::CookedMutton,
+        Item::WhiteBanner,
+        Item::OrangeBanner,
+        Item::MagentaBanner,
+        Item::LightBlueBanner,
+        Item::YellowBanner,
+        Item::LimeBanner,
+        Item::PinkBanner,
+        Item::GrayBanner,
+        Item::LightGrayBanner,
+        Item::CyanBanner,
+        Item::PurpleBanner,
+        Item::BlueBanner,
+        Item::BrownBanner,
+        Item::GreenBanner,
+        Item::RedBanner,
+        Item::BlackBanner,
+        Item::EndCrystal,
+        Item::ChorusFruit,
+        Item::PoppedChorusFruit,
+        Item::Beetroot,
+        Item::BeetrootSeeds,
+        Item::BeetrootSoup,
+        Item::DragonBreath,
+        Item::SplashPotion,
+        Item::SpectralArrow,
+        Item::TippedArrow,
+        Item::LingeringPotion,
+        Item::Shield,
+        Item::Elytra,
+        Item::SpruceBoat,
+        Item::BirchBoat,
+        Item::JungleBoat,
+        Item::AcaciaBoat,
+        Item::DarkOakBoat,
+        Item::TotemOfUndying,
+        Item::ShulkerShell,
+        Item::IronNugget,
+        Item::KnowledgeBook,
+        Item::DebugStick,
+        Item::MusicDisc13,
+        Item::MusicDiscCat,
+        Item::MusicDiscBlocks,
+        Item::MusicDiscChirp,
+        Item::MusicDiscFar,
+        Item::MusicDiscMall,
+        Item::MusicDiscMellohi,
+        Item::MusicDiscStal,
+        Item::MusicDiscStrad,
+        Item::MusicDiscWard,
+        Item::MusicDisc11,
+        Item::MusicDiscWait,
+        Item::Trident,
+        Item::PhantomMembrane,
+        Item::NautilusShell,
+        Item::HeartOfTheSea,
+    ];
+
+    #[doc = " Returns the name of this item, i.e. its identifier"]
+    #[doc = " without the `minecraft:` prefix."]
+    pub fn name(self) -> &'static str {
+        &self.identifier()["minecraft:".len()..]
+    }
+    #[doc = " Returns an iterator over all items paired with their names."]
+    pub fn variants() -> impl Iterator<Item = (Self, &'static str)> {
+        Self::ALL.iter().map(|item| (*item, item.name()))
+    }
 }
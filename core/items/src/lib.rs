@@ -1,13 +1,20 @@
 #![forbid(unsafe_code, warnings)]
 
 use num_traits::{FromPrimitive, ToPrimitive};
+use std::cmp::min;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
 
 #[macro_use]
 extern crate num_derive;
 
+mod food;
 mod item;
+mod tool;
 
+pub use food::FoodProperties;
 pub use item::Item;
+pub use tool::{Tool, ToolMaterial};
 
 impl Item {
     /// Retrieves the 1.13.2 protocol ID for this item.
@@ -25,6 +32,215 @@ impl Item {
     {
         Item::from_i32(id)
     }
+
+    /// Returns the identifier of the sound event played
+    /// by this item, or `None` if it doesn't play one.
+    ///
+    /// Currently, only music discs have a sound event.
+    pub fn sound_event(self) -> Option<&'static str> {
+        match self {
+            Item::MusicDisc13 => Some("minecraft:music_disc.13"),
+            Item::MusicDiscCat => Some("minecraft:music_disc.cat"),
+            Item::MusicDiscBlocks => Some("minecraft:music_disc.blocks"),
+            Item::MusicDiscChirp => Some("minecraft:music_disc.chirp"),
+            Item::MusicDiscFar => Some("minecraft:music_disc.far"),
+            Item::MusicDiscMall => Some("minecraft:music_disc.mall"),
+            Item::MusicDiscMellohi => Some("minecraft:music_disc.mellohi"),
+            Item::MusicDiscStal => Some("minecraft:music_disc.stal"),
+            Item::MusicDiscStrad => Some("minecraft:music_disc.strad"),
+            Item::MusicDiscWard => Some("minecraft:music_disc.ward"),
+            Item::MusicDisc11 => Some("minecraft:music_disc.11"),
+            Item::MusicDiscWait => Some("minecraft:music_disc.wait"),
+            _ => None,
+        }
+    }
+
+    /// Returns the maximum number of items of this
+    /// type which fit in a single stack.
+    pub const fn stack_size(self) -> u8 {
+        match self {
+            Item::WoodenSword
+            | Item::GoldenSword
+            | Item::StoneSword
+            | Item::IronSword
+            | Item::DiamondSword
+            | Item::WoodenAxe
+            | Item::GoldenAxe
+            | Item::StoneAxe
+            | Item::IronAxe
+            | Item::DiamondAxe
+            | Item::WoodenHoe
+            | Item::GoldenHoe
+            | Item::StoneHoe
+            | Item::IronHoe
+            | Item::DiamondHoe
+            | Item::WoodenPickaxe
+            | Item::GoldenPickaxe
+            | Item::StonePickaxe
+            | Item::IronPickaxe
+            | Item::DiamondPickaxe
+            | Item::WoodenShovel
+            | Item::GoldenShovel
+            | Item::StoneShovel
+            | Item::IronShovel
+            | Item::DiamondShovel
+            | Item::LeatherChestplate
+            | Item::GoldenChestplate
+            | Item::ChainmailChestplate
+            | Item::IronChestplate
+            | Item::DiamondChestplate
+            | Item::LeatherLeggings
+            | Item::GoldenLeggings
+            | Item::ChainmailLeggings
+            | Item::IronLeggings
+            | Item::DiamondLeggings
+            | Item::LeatherBoots
+            | Item::GoldenBoots
+            | Item::ChainmailBoots
+            | Item::IronBoots
+            | Item::DiamondBoots
+            | Item::LeatherHelmet
+            | Item::GoldenHelmet
+            | Item::ChainmailHelmet
+            | Item::IronHelmet
+            | Item::DiamondHelmet
+            | Item::Bow
+            | Item::WritableBook
+            | Item::FlintAndSteel
+            | Item::WhiteBed
+            | Item::OrangeBed
+            | Item::MagentaBed
+            | Item::LightBlueBed
+            | Item::YellowBed
+            | Item::LimeBed
+            | Item::PinkBed
+            | Item::GrayBed
+            | Item::LightGrayBed
+            | Item::CyanBed
+            | Item::PurpleBed
+            | Item::BlueBed
+            | Item::BrownBed
+            | Item::GreenBed
+            | Item::RedBed
+            | Item::BlackBed
+            | Item::ShulkerBox
+            | Item::TurtleEgg
+            | Item::TurtleHelmet
+            | Item::FishingRod
+            | Item::EnchantedBook
+            | Item::Potion
+            | Item::LingeringPotion
+            | Item::SplashPotion
+            | Item::WaterBucket
+            | Item::LavaBucket
+            | Item::TropicalFishBucket
+            | Item::CodBucket
+            | Item::MilkBucket
+            | Item::PufferfishBucket
+            | Item::SalmonBucket
+            | Item::CarrotOnAStick
+            | Item::Elytra
+            | Item::Shield
+            | Item::Trident
+            | Item::MusicDisc13
+            | Item::MusicDiscCat
+            | Item::MusicDiscBlocks
+            | Item::MusicDiscChirp
+            | Item::MusicDiscFar
+            | Item::MusicDiscMall
+            | Item::MusicDiscMellohi
+            | Item::MusicDiscStal
+            | Item::MusicDiscStrad
+            | Item::MusicDiscWard
+            | Item::MusicDisc11
+            | Item::MusicDiscWait
+            | Item::TotemOfUndying
+            | Item::Shears
+            | Item::AcaciaBoat
+            | Item::DarkOakBoat
+            | Item::OakBoat
+            | Item::SpruceBoat
+            | Item::BirchBoat
+            | Item::JungleBoat
+            | Item::MushroomStew
+            | Item::BeetrootSoup
+            | Item::RabbitStew
+            | Item::Cake
+            | Item::Minecart
+            | Item::ChestMinecart
+            | Item::CommandBlockMinecart
+            | Item::FurnaceMinecart
+            | Item::HopperMinecart
+            | Item::TntMinecart
+            | Item::DiamondHorseArmor
+            | Item::GoldenHorseArmor
+            | Item::Saddle
+            | Item::KnowledgeBook
+            | Item::DebugStick
+            | Item::IronHorseArmor => 1,
+            Item::EnderPearl
+            | Item::Snowball
+            | Item::WhiteBanner
+            | Item::OrangeBanner
+            | Item::MagentaBanner
+            | Item::LightBlueBanner
+            | Item::YellowBanner
+            | Item::LimeBanner
+            | Item::PinkBanner
+            | Item::GrayBanner
+            | Item::LightGrayBanner
+            | Item::CyanBanner
+            | Item::PurpleBanner
+            | Item::BlueBanner
+            | Item::BrownBanner
+            | Item::GreenBanner
+            | Item::RedBanner
+            | Item::BlackBanner
+            | Item::Sign
+            | Item::ArmorStand
+            | Item::Bucket
+            | Item::WrittenBook
+            | Item::Egg => 16,
+            _ => 64,
+        }
+    }
+
+    /// Returns the number of uses this item has before
+    /// it breaks, or `None` if it does not take damage.
+    pub fn max_durability(self) -> Option<u32> {
+        if let Some(material) = self.tool_material() {
+            return Some(material.durability());
+        }
+
+        match self {
+            Item::LeatherHelmet => Some(55),
+            Item::LeatherChestplate => Some(80),
+            Item::LeatherLeggings => Some(75),
+            Item::LeatherBoots => Some(65),
+            Item::ChainmailHelmet | Item::IronHelmet => Some(165),
+            Item::ChainmailChestplate | Item::IronChestplate => Some(240),
+            Item::ChainmailLeggings | Item::IronLeggings => Some(225),
+            Item::ChainmailBoots | Item::IronBoots => Some(195),
+            Item::GoldenHelmet => Some(77),
+            Item::GoldenChestplate => Some(112),
+            Item::GoldenLeggings => Some(105),
+            Item::GoldenBoots => Some(91),
+            Item::DiamondHelmet => Some(363),
+            Item::DiamondChestplate => Some(528),
+            Item::DiamondLeggings => Some(495),
+            Item::DiamondBoots => Some(429),
+            Item::TurtleHelmet => Some(275),
+            Item::Bow => Some(384),
+            Item::FishingRod => Some(64),
+            Item::FlintAndSteel => Some(64),
+            Item::Shears => Some(238),
+            Item::CarrotOnAStick => Some(25),
+            Item::Shield => Some(336),
+            Item::Elytra => Some(432),
+            Item::Trident => Some(250),
+            _ => None,
+        }
+    }
 }
 
 /// Represents an item stack.
@@ -49,16 +265,225 @@ impl ItemStack {
     pub const fn new(ty: Item, amount: u8) -> Self {
         Self { ty, amount }
     }
+
+    /// Creates a stack, checking that `amount` does not
+    /// exceed the stack size of `ty`.
+    pub fn new_checked(ty: Item, amount: u8) -> Result<Self, ItemStackError> {
+        if amount > ty.stack_size() {
+            return Err(ItemStackError::TooLarge { ty, amount });
+        }
+
+        Ok(Self::new(ty, amount))
+    }
+
+    /// Returns a copy of this stack with the given amount.
+    pub const fn with_amount(self, amount: u8) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Returns whether this stack holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Returns whether this stack can be merged with `other`.
+    ///
+    /// Stacks only hold a type and an amount for now, so this
+    /// compares types; once stacks carry damage or NBT data,
+    /// those must be equal as well.
+    pub fn is_stackable_with(&self, other: &ItemStack) -> bool {
+        self.ty == other.ty
+    }
+
+    /// Removes up to `n` items from this stack and returns
+    /// them as a new stack.
+    ///
+    /// Returns `None` if no items were removed. If every item
+    /// was removed, this stack is left with an amount of zero;
+    /// `Inventory::set_item_at` treats such a stack as empty.
+    pub fn split(&mut self, n: u8) -> Option<ItemStack> {
+        let taken = min(n, self.amount);
+        if taken == 0 {
+            return None;
+        }
+
+        self.amount -= taken;
+        Some(ItemStack::new(self.ty, taken))
+    }
+
+    /// Splits off half of this stack, rounding up, as
+    /// when a player right-clicks a stack.
+    pub fn split_half(&mut self) -> Option<ItemStack> {
+        self.split(self.amount - self.amount / 2)
+    }
+}
+
+/// An error returned when creating an invalid `ItemStack`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemStackError {
+    /// The amount exceeds the stack size of the item type.
+    TooLarge { ty: Item, amount: u8 },
 }
 
+impl Display for ItemStackError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ItemStackError::TooLarge { ty, amount } => write!(
+                f,
+                "stack of {} {:?} exceeds the stack size of {}",
+                amount,
+                ty,
+                ty.stack_size()
+            ),
+        }
+    }
+}
+
+impl Error for ItemStackError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_default() {
+        assert_eq!(Item::default(), Item::Air);
+    }
+
+    #[test]
+    fn test_is_stackable_with() {
+        let stone = ItemStack::new(Item::Stone, 10);
+        assert!(stone.is_stackable_with(&ItemStack::new(Item::Stone, 64)));
+        assert!(!stone.is_stackable_with(&ItemStack::new(Item::Dirt, 10)));
+    }
+
+    #[test]
+    fn test_max_durability() {
+        assert_eq!(Item::DiamondSword.max_durability(), Some(1561));
+        assert_eq!(Item::GoldenPickaxe.max_durability(), Some(32));
+        assert_eq!(Item::IronChestplate.max_durability(), Some(240));
+        assert_eq!(Item::Shears.max_durability(), Some(238));
+        assert_eq!(Item::Stone.max_durability(), None);
+    }
+
+    #[test]
+    fn test_food() {
+        assert_eq!(
+            Item::Apple.food(),
+            Some(FoodProperties {
+                hunger: 4,
+                saturation: 2.4
+            })
+        );
+        assert_eq!(Item::CookedBeef.food().map(|food| food.hunger), Some(8));
+        assert_eq!(Item::Stone.food(), None);
+    }
+
+    #[test]
+    fn test_new_checked() {
+        assert_eq!(
+            ItemStack::new_checked(Item::Stone, 64),
+            Ok(ItemStack::new(Item::Stone, 64))
+        );
+        assert_eq!(
+            ItemStack::new_checked(Item::Stone, 65),
+            Err(ItemStackError::TooLarge {
+                ty: Item::Stone,
+                amount: 65
+            })
+        );
+        assert!(ItemStack::new_checked(Item::EnderPearl, 16).is_ok());
+        assert!(ItemStack::new_checked(Item::EnderPearl, 17).is_err());
+        assert!(ItemStack::new_checked(Item::DiamondSword, 2).is_err());
+    }
+
+    #[test]
+    fn test_with_amount() {
+        let stack = ItemStack::new(Item::Stone, 1).with_amount(32);
+        assert_eq!(stack, ItemStack::new(Item::Stone, 32));
+        assert!(!stack.is_empty());
+        assert!(stack.with_amount(0).is_empty());
+    }
+
+    #[test]
+    fn test_split() {
+        let mut stack = ItemStack::new(Item::Stone, 10);
+        assert_eq!(stack.split(3), Some(ItemStack::new(Item::Stone, 3)));
+        assert_eq!(stack.amount, 7);
+
+        // Over-splitting takes everything that is left.
+        assert_eq!(stack.split(20), Some(ItemStack::new(Item::Stone, 7)));
+        assert_eq!(stack.amount, 0);
+        assert_eq!(stack.split(1), None);
+
+        let mut stack = ItemStack::new(Item::Stone, 5);
+        assert_eq!(stack.split(0), None);
+        assert_eq!(stack.amount, 5);
+    }
+
+    #[test]
+    fn test_split_half() {
+        let mut even = ItemStack::new(Item::Dirt, 64);
+        assert_eq!(even.split_half(), Some(ItemStack::new(Item::Dirt, 32)));
+        assert_eq!(even.amount, 32);
+
+        let mut odd = ItemStack::new(Item::Dirt, 5);
+        assert_eq!(odd.split_half(), Some(ItemStack::new(Item::Dirt, 3)));
+        assert_eq!(odd.amount, 2);
+
+        let mut single = ItemStack::new(Item::Dirt, 1);
+        assert_eq!(single.split_half(), Some(ItemStack::new(Item::Dirt, 1)));
+        assert_eq!(single.amount, 0);
+    }
+
     #[test]
     fn test_item() {
         let item = Item::Air;
         assert_eq!(item.native_protocol_id(), 0);
         assert_eq!(Item::from_native_protocol_id(0), Some(item));
     }
+
+    #[test]
+    fn test_sound_event() {
+        assert_eq!(
+            Item::MusicDiscCat.sound_event(),
+            Some("minecraft:music_disc.cat")
+        );
+        assert_eq!(Item::Stone.sound_event(), None);
+    }
+
+    #[test]
+    fn test_variants() {
+        let variants: Vec<_> = Item::variants().collect();
+        assert_eq!(variants.len(), Item::ALL.len());
+
+        for (i, (item, name)) in variants.into_iter().enumerate() {
+            assert_eq!(item.native_protocol_id(), i as i32);
+            assert_eq!(item.name(), name);
+            assert_eq!(item.identifier(), format!("minecraft:{}", name));
+        }
+        assert_eq!(Item::DiamondSword.name(), "diamond_sword");
+    }
+
+    #[test]
+    fn test_translation_key() {
+        assert_eq!(Item::Stone.translation_key(), "block.minecraft.stone");
+        assert_eq!(Item::OakLog.translation_key(), "block.minecraft.oak_log");
+        assert_eq!(Item::Stick.translation_key(), "item.minecraft.stick");
+        assert_eq!(
+            Item::DiamondSword.translation_key(),
+            "item.minecraft.diamond_sword"
+        );
+    }
+
+    #[test]
+    fn test_const_accessors() {
+        const STICK: &str = Item::Stick.identifier();
+        const STICK_KEY: &str = Item::Stick.translation_key();
+        const SWORD_STACK_SIZE: u8 = Item::DiamondSword.stack_size();
+
+        assert_eq!(STICK, "minecraft:stick");
+        assert_eq!(STICK_KEY, "item.minecraft.stick");
+        assert_eq!(SWORD_STACK_SIZE, 1);
+    }
 }
@@ -0,0 +1,195 @@
+//! Tools and the materials they are made from.
+
+use crate::Item;
+
+/// A kind of tool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Tool {
+    Pickaxe,
+    Axe,
+    Shovel,
+    Hoe,
+    Sword,
+    Shears,
+}
+
+impl Tool {
+    /// Returns the base attack damage dealt by this tool when
+    /// made from the given material, before enchantments and
+    /// critical hits.
+    ///
+    /// Shears have no material, so `material` is ignored for them.
+    pub fn attack_damage(self, material: ToolMaterial) -> f64 {
+        let bonus = material.attack_damage_bonus();
+        match self {
+            Tool::Sword => 4.0 + bonus,
+            Tool::Pickaxe => 2.0 + bonus,
+            Tool::Shovel => 2.5 + bonus,
+            // Axes don't follow the material bonus.
+            Tool::Axe => match material {
+                ToolMaterial::Wood | ToolMaterial::Gold => 7.0,
+                ToolMaterial::Stone | ToolMaterial::Iron | ToolMaterial::Diamond => 9.0,
+            },
+            Tool::Hoe | Tool::Shears => 1.0,
+        }
+    }
+}
+
+/// A material from which tools are crafted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ToolMaterial {
+    Wood,
+    Stone,
+    Iron,
+    Diamond,
+    Gold,
+}
+
+impl ToolMaterial {
+    /// Returns the base durability of tools made from this
+    /// material, i.e. the number of uses before enchantments
+    /// (such as Unbreaking) are taken into account.
+    pub fn durability(self) -> u32 {
+        match self {
+            ToolMaterial::Wood => 59,
+            ToolMaterial::Stone => 131,
+            ToolMaterial::Iron => 250,
+            ToolMaterial::Diamond => 1561,
+            ToolMaterial::Gold => 32,
+        }
+    }
+
+    /// Returns the enchantability of this material. Higher
+    /// values yield better enchantments at an enchanting table.
+    pub fn enchantability(self) -> u32 {
+        match self {
+            ToolMaterial::Wood => 15,
+            ToolMaterial::Stone => 5,
+            ToolMaterial::Iron => 14,
+            ToolMaterial::Diamond => 10,
+            ToolMaterial::Gold => 22,
+        }
+    }
+
+    /// Additional attack damage added to most tools made
+    /// from this material.
+    fn attack_damage_bonus(self) -> f64 {
+        match self {
+            ToolMaterial::Wood | ToolMaterial::Gold => 0.0,
+            ToolMaterial::Stone => 1.0,
+            ToolMaterial::Iron => 2.0,
+            ToolMaterial::Diamond => 3.0,
+        }
+    }
+}
+
+impl Item {
+    /// Returns the kind of tool this item is, or `None`
+    /// if it is not a tool.
+    pub fn tool(self) -> Option<Tool> {
+        match self {
+            Item::WoodenPickaxe
+            | Item::StonePickaxe
+            | Item::IronPickaxe
+            | Item::GoldenPickaxe
+            | Item::DiamondPickaxe => Some(Tool::Pickaxe),
+            Item::WoodenAxe
+            | Item::StoneAxe
+            | Item::IronAxe
+            | Item::GoldenAxe
+            | Item::DiamondAxe => Some(Tool::Axe),
+            Item::WoodenShovel
+            | Item::StoneShovel
+            | Item::IronShovel
+            | Item::GoldenShovel
+            | Item::DiamondShovel => Some(Tool::Shovel),
+            Item::WoodenHoe
+            | Item::StoneHoe
+            | Item::IronHoe
+            | Item::GoldenHoe
+            | Item::DiamondHoe => Some(Tool::Hoe),
+            Item::WoodenSword
+            | Item::StoneSword
+            | Item::IronSword
+            | Item::GoldenSword
+            | Item::DiamondSword => Some(Tool::Sword),
+            Item::Shears => Some(Tool::Shears),
+            _ => None,
+        }
+    }
+
+    /// Returns the material this tool is made from, or `None`
+    /// if the item is not a tool or has no material (e.g. shears).
+    pub fn tool_material(self) -> Option<ToolMaterial> {
+        match self {
+            Item::WoodenPickaxe
+            | Item::WoodenAxe
+            | Item::WoodenShovel
+            | Item::WoodenHoe
+            | Item::WoodenSword => Some(ToolMaterial::Wood),
+            Item::StonePickaxe
+            | Item::StoneAxe
+            | Item::StoneShovel
+            | Item::StoneHoe
+            | Item::StoneSword => Some(ToolMaterial::Stone),
+            Item::IronPickaxe
+            | Item::IronAxe
+            | Item::IronShovel
+            | Item::IronHoe
+            | Item::IronSword => Some(ToolMaterial::Iron),
+            Item::GoldenPickaxe
+            | Item::GoldenAxe
+            | Item::GoldenShovel
+            | Item::GoldenHoe
+            | Item::GoldenSword => Some(ToolMaterial::Gold),
+            Item::DiamondPickaxe
+            | Item::DiamondAxe
+            | Item::DiamondShovel
+            | Item::DiamondHoe
+            | Item::DiamondSword => Some(ToolMaterial::Diamond),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durability() {
+        assert_eq!(ToolMaterial::Diamond.durability(), 1561);
+        assert_eq!(ToolMaterial::Gold.durability(), 32);
+    }
+
+    #[test]
+    fn item_tool() {
+        assert_eq!(Item::DiamondPickaxe.tool(), Some(Tool::Pickaxe));
+        assert_eq!(
+            Item::DiamondPickaxe.tool_material(),
+            Some(ToolMaterial::Diamond)
+        );
+
+        assert_eq!(Item::Shears.tool(), Some(Tool::Shears));
+        assert_eq!(Item::Shears.tool_material(), None);
+
+        assert_eq!(Item::Stone.tool(), None);
+        assert_eq!(Item::Stone.tool_material(), None);
+    }
+
+    #[test]
+    fn enchantability() {
+        assert_eq!(ToolMaterial::Gold.enchantability(), 22);
+        assert_eq!(ToolMaterial::Stone.enchantability(), 5);
+    }
+
+    #[test]
+    fn attack_damage() {
+        assert_eq!(Tool::Sword.attack_damage(ToolMaterial::Diamond), 7.0);
+        assert_eq!(Tool::Sword.attack_damage(ToolMaterial::Wood), 4.0);
+        assert_eq!(Tool::Axe.attack_damage(ToolMaterial::Stone), 9.0);
+        assert_eq!(Tool::Shovel.attack_damage(ToolMaterial::Iron), 4.5);
+        assert_eq!(Tool::Pickaxe.attack_damage(ToolMaterial::Gold), 2.0);
+        assert_eq!(Tool::Hoe.attack_damage(ToolMaterial::Diamond), 1.0);
+    }
+}
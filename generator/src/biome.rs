@@ -1,5 +1,6 @@
 //! Generation of biome mappings from 1.14 registry report.
 
+use crate::util::run_rustfmt;
 use failure::Error;
 use heck::CamelCase;
 use indexmap::IndexMap;
@@ -7,7 +8,6 @@ use proc_macro2::{Ident, Span};
 use serde_json::Value;
 use std::fs::File;
 use std::io::{Read, Write};
-use std::process::Command;
 
 #[derive(Deserialize, Clone)]
 pub struct BiomeReport {
@@ -120,7 +120,7 @@ pub fn generate_rust(input: &str, output: &str) -> Result<(), Error> {
     let mut file = File::create(output)?;
     file.write_all(code.to_string().as_bytes())?;
 
-    Command::new("rustfmt").arg(output).output()?;
+    run_rustfmt(output)?;
 
     Ok(())
 }
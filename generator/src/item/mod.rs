@@ -1,10 +1,11 @@
 //! Handles item ID mapping generation.
 
+use crate::util::run_rustfmt;
+use crate::BlockReport;
 use failure::Error;
 use indexmap::IndexMap;
 use std::fs::File;
-use std::io::{Read, Write};
-use std::process::Command;
+use std::io::{BufReader, Read, Write};
 
 mod mappings;
 mod rust;
@@ -47,19 +48,20 @@ pub fn generate_mappings_file(input: &str, output: &str) -> Result<(), Error> {
     Ok(())
 }
 
-pub fn generate_rust(input: &str, output: &str) -> Result<(), Error> {
-    info!("Parsing data file");
+pub fn generate_rust(input: &str, blocks: &str, output: &str) -> Result<(), Error> {
+    info!("Parsing data files");
     let report = load_report(input)?;
-    info!("Data file parsed successfully");
+    let blocks: BlockReport = serde_json::from_reader(BufReader::new(File::open(blocks)?))?;
+    info!("Data files parsed successfully");
 
     info!("Generating Rust code");
-    let buf = rust::generate_rust(report)?;
+    let buf = rust::generate_rust(report, &blocks)?;
     let mut file = File::create(output)?;
     file.write_all(buf.as_bytes())?;
     info!("Generated code");
 
     info!("Formatting code with rustfmt");
-    Command::new("rustfmt").arg(output).output()?;
+    run_rustfmt(output)?;
     info!("Success");
 
     Ok(())
@@ -1,14 +1,17 @@
 //! Generates Rust code for `Item` enum.
 
 use crate::item::ItemReport;
+use crate::BlockReport;
 use failure::Error;
 use heck::CamelCase;
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Literal, Span};
 
-pub fn generate_rust(report: ItemReport) -> Result<String, Error> {
+pub fn generate_rust(report: ItemReport, blocks: &BlockReport) -> Result<String, Error> {
     let mut enum_variants = vec![];
     let mut from_identifier_arms = vec![];
     let mut to_identifier_arms = vec![];
+    let mut translation_key_arms = vec![];
+    let mut all_variants = vec![];
 
     for (identifier, _) in report.mappings {
         let variant_name = ident(&variant_name(&identifier));
@@ -16,6 +19,10 @@ pub fn generate_rust(report: ItemReport) -> Result<String, Error> {
             #variant_name
         });
 
+        all_variants.push(quote! {
+            Item::#variant_name
+        });
+
         from_identifier_arms.push(quote! {
             #identifier => Some(Item::#variant_name)
         });
@@ -23,14 +30,35 @@ pub fn generate_rust(report: ItemReport) -> Result<String, Error> {
         to_identifier_arms.push(quote! {
             Item::#variant_name => #identifier
         });
+
+        // Items which place a block of the same name
+        // share that block's translation key.
+        let translation_key = if blocks.blocks.contains_key(&identifier) {
+            format!("block.minecraft.{}", strip_prefix(&identifier))
+        } else {
+            format!("item.minecraft.{}", strip_prefix(&identifier))
+        };
+        translation_key_arms.push(quote! {
+            Item::#variant_name => #translation_key
+        });
     }
 
+    let count = Literal::usize_unsuffixed(all_variants.len());
+
     let result = quote! {
+        #![allow(clippy::all)]
+
         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ToPrimitive, FromPrimitive)]
         pub enum Item {
             #(#enum_variants, )*
         }
 
+        impl Default for Item {
+            fn default() -> Self {
+                Item::Air
+            }
+        }
+
         impl Item {
             pub fn from_identifier(identifier: &str) -> Option<Self> {
                 match identifier {
@@ -39,11 +67,37 @@ pub fn generate_rust(report: ItemReport) -> Result<String, Error> {
                 }
             }
 
-            pub fn identifier(self) -> &'static str {
+            pub const fn identifier(self) -> &'static str {
                 match self {
                     #(#to_identifier_arms, )*
                 }
             }
+
+            /// Returns the translation key of this item.
+            ///
+            /// Items which place a block share the block's key,
+            /// e.g. `block.minecraft.stone`; other items use
+            /// `item.minecraft.<name>`.
+            pub const fn translation_key(self) -> &'static str {
+                match self {
+                    #(#translation_key_arms, )*
+                }
+            }
+
+            pub const ALL: [Item; #count] = [
+                #(#all_variants, )*
+            ];
+
+            /// Returns the name of this item, i.e. its identifier
+            /// without the `minecraft:` prefix.
+            pub fn name(self) -> &'static str {
+                &self.identifier()["minecraft:".len()..]
+            }
+
+            /// Returns an iterator over all items paired with their names.
+            pub fn variants() -> impl Iterator<Item = (Self, &'static str)> {
+                Self::ALL.iter().map(|item| (*item, item.name()))
+            }
         }
     };
 
@@ -63,3 +117,41 @@ fn variant_name(identifier: &str) -> String {
 fn ident(s: &str) -> Ident {
     Ident::new(s, Span::call_site())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Item;
+
+    fn report() -> ItemReport {
+        let mut report = ItemReport {
+            mappings: Default::default(),
+        };
+        report
+            .mappings
+            .insert("minecraft:stone".to_owned(), Item { protocol_id: 1 });
+        report
+            .mappings
+            .insert("minecraft:stick".to_owned(), Item { protocol_id: 2 });
+        report
+    }
+
+    fn blocks() -> BlockReport {
+        serde_json::from_str(r#"{ "minecraft:stone": { "states": [{ "id": 1 }] } }"#).unwrap()
+    }
+
+    #[test]
+    fn allows_clippy_lints() {
+        let code = generate_rust(report(), &blocks()).unwrap();
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        assert!(code.starts_with("#![allow(clippy::all)]"));
+    }
+
+    #[test]
+    fn translation_keys() {
+        let code = generate_rust(report(), &blocks()).unwrap();
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        assert!(code.contains(r#"Item::Stone=>"block.minecraft.stone""#));
+        assert!(code.contains(r#"Item::Stick=>"item.minecraft.stick""#));
+    }
+}
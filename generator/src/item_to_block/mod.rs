@@ -3,13 +3,13 @@
 //! and breaks.
 
 use crate::item::ItemReport;
+use crate::util::run_rustfmt;
 use crate::BlockReport;
 use failure::Error;
 use heck::CamelCase;
 use proc_macro2::{Ident, Span};
 use std::fs::File;
 use std::io::{Read, Write};
-use std::process::Command;
 
 /// Given a block report and an item report, generates
 /// mappings from items to blocks and writes them to
@@ -33,7 +33,7 @@ pub fn generate_mappings(blocks: &str, items: &str, output_path: &str) -> Result
 
     _internal_generate_mappings(&blocks, &items, &mut output)?;
 
-    Command::new("rustfmt").arg(output_path).output()?;
+    run_rustfmt(output_path)?;
 
     Ok(())
 }
@@ -78,7 +78,11 @@ fn _internal_generate_mappings(
         }
 
         pub fn block_to_item(block: BlockId) -> Option<Item> {
-            match block.kind() {
+            kind_to_item(block.kind())
+        }
+
+        pub fn kind_to_item(kind: BlockKind) -> Option<Item> {
+            match kind {
                 #(#block_to_item_match_arms ,)*
                 _ => None,
             }
@@ -89,6 +89,7 @@ fn run() -> Result<(), Error> {
             let args = matches.subcommand_matches("item-rust").unwrap();
             item::generate_rust(
                 args.value_of("input").unwrap(),
+                args.value_of("blocks").unwrap(),
                 args.value_of("output").unwrap(),
             )?;
         }
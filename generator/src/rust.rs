@@ -3,8 +3,8 @@
 //! written - but at least it works.
 
 use super::*;
+use crate::util::run_rustfmt;
 use quote::ToTokens;
-use std::process::Command;
 use syn::LitInt;
 
 pub fn generate_rust_code(input: &str, output: &str) -> Result<(), Error> {
@@ -179,12 +179,6 @@ pub fn generate_rust_code(input: &str, output: &str) -> Result<(), Error> {
     Ok(())
 }
 
-fn run_rustfmt(file: &str) -> Result<(), Error> {
-    Command::new("rustfmt").args(&[file]).output()?;
-
-    Ok(())
-}
-
 fn generate_block_code(
     block: &Block,
     block_name: &str,
@@ -1,3 +1,8 @@
+use failure::{format_err, Error};
+use std::fs;
+use std::io;
+use std::process::Command;
+
 /// Calculates the product of all values in the given slice.
 pub fn slice_product(slice: &[usize]) -> usize {
     let mut result = 1;
@@ -34,3 +39,41 @@ pub fn min_in_slice<O: Ord + Copy>(slice: &[O]) -> O {
 
     lowest
 }
+
+/// Formats the given file with `rustfmt`, then normalizes
+/// its line endings to LF.
+///
+/// A missing `rustfmt` binary is reported separately
+/// from a failure to format the file.
+pub fn run_rustfmt(file: &str) -> Result<(), Error> {
+    let output = match Command::new("rustfmt").arg(file).output() {
+        Ok(output) => output,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(format_err!(
+                "rustfmt was not found. Install it with `rustup component add rustfmt`"
+            ))
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    if !output.status.success() {
+        return Err(format_err!(
+            "rustfmt failed to format {}: {}",
+            file,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    normalize_line_endings(file)
+}
+
+/// Rewrites the given file with LF line endings, so that
+/// generated code is identical regardless of platform.
+pub fn normalize_line_endings(file: &str) -> Result<(), Error> {
+    let contents = fs::read_to_string(file)?;
+    if contents.contains('\r') {
+        fs::write(file, contents.replace("\r\n", "\n"))?;
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 use crate::{chunk_manager, ChunkWorkerHandle};
 use feather_core::anvil::entity::BaseEntityData;
-use feather_core::anvil::player::{InventorySlot, PlayerData};
+use feather_core::anvil::player::{AnvilInventory, PlayerData};
 use feather_core::inventory::Inventory;
 use feather_core::util::{ChunkPosition, Gamemode, Position, Vec3d};
 use feather_server_types::{
@@ -150,18 +150,7 @@ pub fn on_player_leave_save_data(event: &PlayerLeaveEvent, game: &Game, world: &
 }
 
 pub fn save_player_data(game: &Game, world: &World, player: Entity) {
-    let inventory = world
-        .get::<Inventory>(player)
-        .items()
-        .iter()
-        .enumerate()
-        .filter_map(|(i, item)| item.map(|item| (i, item)))
-        .map(|(slot, item)| InventorySlot {
-            count: item.amount as i8,
-            slot: slot as i8,
-            item: item.ty.identifier().to_owned(),
-        })
-        .collect();
+    let inventory = world.get::<Inventory>(player).to_anvil_slots();
 
     let data = PlayerData {
         entity: BaseEntityData::new(*world.get::<Position>(player), Vec3d::broadcast(0.0)),
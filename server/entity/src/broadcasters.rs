@@ -11,3 +11,74 @@ pub use entity_deletion::*;
 pub use item_collect::*;
 pub use metadata::*;
 pub use movement::*;
+
+#[cfg(test)]
+mod tests {
+    use feather_core::network::packets::{EntityStatus, NamedSoundEffect, TimeUpdate};
+    use feather_server_types::SoundCategory;
+    use feather_test_framework::Test;
+
+    #[test]
+    fn broadcast_to_tracking() {
+        let mut test = Test::new();
+
+        let player1 = test.player("", position!(0.0, 64.0, 0.0));
+        let player2 = test.player("", position!(45.0, 1000.0, -37.9));
+        let player3 = test.player("", position!(1000.0, -450.0, 100.0));
+
+        let packet = TimeUpdate {
+            world_age: 10,
+            time_of_day: 20,
+        };
+        test.game
+            .broadcast_to_tracking(&test.world, packet, player1, Some(player1));
+
+        assert!(test.sent::<TimeUpdate>(player1).is_none());
+        assert!(test.sent::<TimeUpdate>(player3).is_none());
+        let packet = test.sent::<TimeUpdate>(player2).unwrap();
+        assert_eq!(packet.world_age, 10);
+        assert_eq!(packet.time_of_day, 20);
+
+        // Matches the receivers of an entity update.
+        let packet = EntityStatus {
+            entity_id: test.id(player1),
+            entity_status: 2,
+        };
+        test.game
+            .broadcast_entity_update(&test.world, packet, player1, Some(player1));
+
+        assert!(test.sent::<EntityStatus>(player1).is_none());
+        assert!(test.sent::<EntityStatus>(player3).is_none());
+        assert!(test.sent::<EntityStatus>(player2).is_some());
+    }
+    #[test]
+    fn broadcast_sound() {
+        let mut test = Test::new();
+
+        let near = test.player("", position!(0.0, 64.0, 0.0));
+        let far = test.player("", position!(1000.0, 64.0, 100.0));
+
+        test.game.broadcast_sound(
+            &test.world,
+            position!(2.5, 65.0, -1.0),
+            "minecraft:block.stone.break",
+            SoundCategory::Blocks,
+            1.0,
+            0.8,
+        );
+
+        assert!(test.sent::<NamedSoundEffect>(far).is_none());
+        let packet = test.sent::<NamedSoundEffect>(near).unwrap();
+        assert_eq!(packet.sound_name, "minecraft:block.stone.break");
+        assert_eq!(packet.sound_category, 4);
+        assert_eq!(
+            (
+                packet.effect_pos_x,
+                packet.effect_pos_y,
+                packet.effect_pos_z
+            ),
+            (20, 520, -8)
+        );
+        assert_eq!(packet.pitch, 0.8);
+    }
+}
@@ -2,8 +2,8 @@ use feather_core::entitymeta::EntityMetadata;
 use feather_core::network::packets::PacketEntityMetadata;
 use feather_core::util::Position;
 use feather_server_types::{
-    CreationPacketCreator, EntitySendEvent, EntitySpawnEvent, Game, Network, NetworkId,
-    PlayerJoinEvent, SpawnPacketCreator,
+    ChunkCrossEvent, CreationPacketCreator, EntitySendEvent, EntitySpawnEvent, Game, Network,
+    NetworkId, Player, PlayerJoinEvent, SpawnPacketCreator,
 };
 use fecs::{IntoQuery, Read, World};
 
@@ -62,6 +62,69 @@ pub fn on_entity_spawn_send_to_clients(
     }
 }
 
+/// When a non-player entity moves into another chunk, sends
+/// it to players who are now able to see it.
+///
+/// Players are sent by `on_chunk_cross_update_entities`
+/// in the player crate instead.
+#[fecs::event_handler]
+pub fn on_chunk_cross_send_to_new_trackers(
+    event: &ChunkCrossEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    let old = match event.old {
+        Some(old) => old,
+        None => return, // sent by `on_entity_spawn_send_to_clients`
+    };
+    if world.has::<Player>(event.entity) {
+        return;
+    }
+    let creator = match world.try_get::<SpawnPacketCreator>(event.entity) {
+        Some(creator) => creator,
+        None => return,
+    };
+
+    let old_trackers = game.chunk_holders.holders_for(old);
+    let new_trackers = game
+        .chunk_holders
+        .holders_for(event.new)
+        .iter()
+        .filter(|player| !old_trackers.contains(*player))
+        .filter(|player| world.has::<Network>(**player))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let accessor = world.entity(event.entity).expect("entity does not exist");
+    let meta = world
+        .try_get::<EntityMetadata>(event.entity)
+        .map(|meta| PacketEntityMetadata {
+            entity_id: world.get::<NetworkId>(event.entity).0,
+            metadata: (&*meta).clone(),
+        });
+
+    for player in &new_trackers {
+        let network = world.get::<Network>(*player);
+        // As in `on_entity_spawn_send_to_clients`, metadata goes first.
+        if let Some(meta) = &meta {
+            network.send(meta.clone());
+        }
+        network.send_boxed(creator.get(&accessor));
+    }
+
+    drop(creator);
+
+    for client in new_trackers {
+        game.handle(
+            world,
+            EntitySendEvent {
+                entity: event.entity,
+                client,
+            },
+        );
+    }
+}
+
 /// Wehn a player joins, sends existing entities to the player.
 ///
 /// This only handles init packets (PlayerInfo, etc.)—spawn packets
@@ -82,8 +145,9 @@ pub fn on_player_join_send_existing_entities(event: &PlayerJoinEvent, world: &mu
 mod tests {
     use super::*;
     use crate::item;
+    use crate::on_chunk_cross_destroy_for_former_trackers;
     use feather_core::items::{Item, ItemStack};
-    use feather_core::network::packets::{PlayerInfo, SpawnObject};
+    use feather_core::network::packets::{DestroyEntities, PlayerInfo, SpawnObject};
     use feather_core::position;
     use feather_test_framework::Test;
     use std::collections::HashSet;
@@ -109,6 +173,44 @@ mod tests {
         assert!(test.sent::<SpawnObject>(player2).is_none());
     }
 
+    #[test]
+    fn send_to_new_trackers() {
+        let mut test = Test::new();
+
+        let player = test.player("", Position::default());
+        let item = test.entity(
+            item::create(ItemStack::new(Item::Sand, 1), 0).with(position!(10.0, 64.0, 0.0)),
+        );
+
+        let move_item = |test: &mut Test, pos: Position| {
+            let old = test.world.get::<Position>(item).chunk();
+            test.position(item, pos);
+            let event = ChunkCrossEvent {
+                old: Some(old),
+                new: pos.chunk(),
+                entity: item,
+            };
+            test.handle(event, on_chunk_cross_destroy_for_former_trackers);
+            test.handle(event, on_chunk_cross_send_to_new_trackers);
+        };
+
+        // Still within view.
+        move_item(&mut test, position!(40.0, 64.0, 0.0));
+        assert!(test.sent::<SpawnObject>(player).is_none());
+
+        // Out of view.
+        move_item(&mut test, position!(10000.0, 64.0, 0.0));
+        assert!(test.sent::<DestroyEntities>(player).is_some());
+        assert!(test.sent::<SpawnObject>(player).is_none());
+
+        // Back into view.
+        move_item(&mut test, position!(10.0, 64.0, 0.0));
+        let packet = test.sent::<SpawnObject>(player).unwrap();
+        assert_eq!(packet.entity_id, test.id(item));
+        assert!(test.sent::<PacketEntityMetadata>(player).is_some());
+        assert!(test.sent::<DestroyEntities>(player).is_none());
+    }
+
     #[test]
     fn send_existing_entities() {
         let mut test = Test::new();
@@ -1,5 +1,8 @@
 use feather_core::network::packets::{DestroyEntities, PlayerInfo, PlayerInfoAction};
-use feather_server_types::{EntityDespawnEvent, Game, NetworkId, Player, Uuid};
+use feather_server_types::{
+    ChunkCrossEvent, EntityClientRemoveEvent, EntityDespawnEvent, Game, Network, NetworkId, Player,
+    Uuid,
+};
 use fecs::World;
 
 /// Broadcasts when an entity is deleted.
@@ -9,12 +12,7 @@ pub fn on_entity_despawn_broadcast_despawn(
     game: &mut Game,
     world: &mut World,
 ) {
-    let id = world.get::<NetworkId>(event.entity).0;
-    let packet = DestroyEntities {
-        entity_ids: vec![id],
-    };
-
-    game.broadcast_entity_update(world, packet, event.entity, Some(event.entity));
+    game.broadcast_destroy_entity(world, event.entity, Some(event.entity));
 
     // If the entity was a player, send Player Info to
     // remove them from the tablist.
@@ -29,6 +27,53 @@ pub fn on_entity_despawn_broadcast_despawn(
     }
 }
 
+/// When a non-player entity moves into another chunk, despawns
+/// it for players who are no longer able to see it.
+///
+/// Players are despawned by `on_chunk_cross_update_entities`
+/// in the player crate instead.
+#[fecs::event_handler]
+pub fn on_chunk_cross_destroy_for_former_trackers(
+    event: &ChunkCrossEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    let old = match event.old {
+        Some(old) => old,
+        None => return, // the entity was just spawned
+    };
+    if world.has::<Player>(event.entity) || !world.has::<NetworkId>(event.entity) {
+        return;
+    }
+
+    let new_trackers = game.chunk_holders.holders_for(event.new);
+    let former_trackers = game
+        .chunk_holders
+        .holders_for(old)
+        .iter()
+        .filter(|player| !new_trackers.contains(*player))
+        .filter(|player| world.has::<Network>(**player))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let packet = DestroyEntities {
+        entity_ids: vec![world.get::<NetworkId>(event.entity).0],
+    };
+    for player in &former_trackers {
+        world.get::<Network>(*player).send(packet.clone());
+    }
+
+    for client in former_trackers {
+        game.handle(
+            world,
+            EntityClientRemoveEvent {
+                entity: event.entity,
+                client,
+            },
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,4 +123,35 @@ mod tests {
             assert_eq!(packet.action, PlayerInfoAction::RemovePlayer);
         }
     }
+    #[test]
+    fn destroy_for_former_trackers() {
+        let mut test = Test::new();
+
+        let player = test.player("", Position::default());
+        let item =
+            test.entity(item::create(ItemStack::default(), 0).with(position!(10.0, 64.0, 0.0)));
+
+        let move_item = |test: &mut Test, pos: Position| {
+            let old = test.world.get::<Position>(item).chunk();
+            test.position(item, pos);
+            test.handle(
+                ChunkCrossEvent {
+                    old: Some(old),
+                    new: pos.chunk(),
+                    entity: item,
+                },
+                on_chunk_cross_destroy_for_former_trackers,
+            );
+        };
+
+        // Still within view.
+        move_item(&mut test, position!(40.0, 64.0, 0.0));
+        assert!(test.sent::<DestroyEntities>(player).is_none());
+
+        // Out of view.
+        move_item(&mut test, position!(10000.0, 64.0, 0.0));
+
+        let packet = test.sent::<DestroyEntities>(player).unwrap();
+        assert_eq!(packet.entity_ids, vec![test.id(item)]);
+    }
 }
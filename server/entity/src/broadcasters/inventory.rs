@@ -1,13 +1,14 @@
 //! Broadcasting of inventory-related events.
 
 use crate::inventory::Equipment;
-use feather_core::inventory::{Inventory, SlotIndex, SLOT_HOTBAR_OFFSET};
+use feather_core::inventory::{classify, Inventory, InventorySlot, SlotIndex};
+use feather_core::items::ItemStack;
 use feather_core::network::packets::{EntityEquipment, SetSlot};
 use feather_server_types::{
-    EntitySendEvent, Game, HeldItem, InventoryUpdateEvent, Network, NetworkId,
+    EntityEquipmentBroadcastEvent, EntitySendEvent, Game, HeldItem, HeldItemChangeEvent,
+    InventoryUpdateEvent, Network, NetworkId,
 };
-use fecs::World;
-use num_traits::ToPrimitive;
+use fecs::{Entity, World};
 
 /// System for broadcasting equipment updates.
 #[fecs::event_handler]
@@ -16,26 +17,76 @@ pub fn on_inventory_update_broadcast_equipment_update(
     game: &mut Game,
     world: &mut World,
 ) {
-    let inv = world.get::<Inventory>(event.player);
-    let held_item = world.get::<HeldItem>(event.player);
+    let held_item = world.get::<HeldItem>(event.player).0;
 
     for slot in &event.slots {
         // Skip this slot if it is not an equipment update.
-        if let Ok(equipment) = is_equipment_update(held_item.0, *slot) {
-            let slot = equipment.slot_index(held_item.0);
-            let item = inv.item_at(slot).cloned();
+        if let Ok(equipment) = is_equipment_update(held_item, *slot) {
+            let slot = equipment.slot_index(held_item);
+            let item = world.get::<Inventory>(event.player).item_at(slot).cloned();
 
-            let packet = EntityEquipment {
-                entity_id: world.get::<NetworkId>(event.player).0,
-                slot: equipment.to_i32().unwrap(),
-                item,
-            };
-
-            game.broadcast_entity_update(world, packet, event.player, Some(event.player));
+            if let Some(packet) = equipment_packet(game, world, event.player, equipment, item) {
+                game.broadcast_entity_update(world, packet, event.player, Some(event.player));
+            }
         }
     }
 }
 
+/// System for broadcasting the new main hand item
+/// when a player changes their held item.
+#[fecs::event_handler]
+pub fn on_held_item_change_broadcast_equipment_update(
+    event: &HeldItemChangeEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    let (old, new) = {
+        let inv = world.get::<Inventory>(event.player);
+        (
+            inv.item_at(Equipment::MainHand.slot_index(event.old_slot))
+                .copied(),
+            inv.item_at(Equipment::MainHand.slot_index(event.new_slot))
+                .copied(),
+        )
+    };
+
+    // Other players can't tell the difference if both slots hold the same stack.
+    if old == new {
+        return;
+    }
+
+    if let Some(packet) = equipment_packet(game, world, event.player, Equipment::MainHand, new) {
+        game.broadcast_entity_update(world, packet, event.player, Some(event.player));
+    }
+}
+
+/// Creates the Entity Equipment packet to broadcast for
+/// an entity's equipment, first triggering an
+/// `EntityEquipmentBroadcastEvent` so that handlers can
+/// rewrite the item.
+///
+/// Returns `None` if a handler cancelled the broadcast.
+fn equipment_packet(
+    game: &mut Game,
+    world: &mut World,
+    entity: Entity,
+    equipment: Equipment,
+    item: Option<ItemStack>,
+) -> Option<EntityEquipment> {
+    let event = EntityEquipmentBroadcastEvent::new(entity, equipment, item);
+    game.handle(world, event.clone());
+
+    if event.is_cancelled() {
+        return None;
+    }
+
+    Some(EntityEquipment {
+        entity_id: world.get::<NetworkId>(entity).0,
+        slot: i32::from(equipment),
+        item: event.item(),
+    })
+}
+
 /// System to send an entity's equipment when the
 /// entity is sent to a client.
 #[fecs::event_handler]
@@ -51,64 +102,64 @@ pub fn on_entity_send_send_equipment(event: &EntitySendEvent, world: &mut World)
         Some(inv) => inv,
         None => return, // no equipment to send
     };
-    let held_item = world.get::<HeldItem>(entity);
-
-    let equipments = [
-        Equipment::MainHand,
-        Equipment::Boots,
-        Equipment::Leggings,
-        Equipment::Chestplate,
-        Equipment::Helmet,
-        Equipment::OffHand,
-    ];
-
-    for equipment in equipments.iter() {
-        let item = {
-            let slot = equipment.slot_index(held_item.0);
-            match inventory.item_at(slot).copied() {
-                Some(item) => item,
-                None => continue, // don't send equipment if it doesn't exist
-            }
-        };
-
-        let equipment_slot = equipment.to_i32().unwrap();
+    let held_item = world.get::<HeldItem>(entity).0;
+    let entity_id = world.get::<NetworkId>(entity).0;
 
-        let packet = EntityEquipment {
-            entity_id: world.get::<NetworkId>(entity).0,
-            slot: equipment_slot,
-            item: Some(item),
-        };
-        network.send(packet);
+    // Only occupied slots are visited, so empty equipment isn't sent.
+    for (slot, item) in inventory.iter() {
+        if let Ok(equipment) = is_equipment_update(held_item, slot) {
+            let packet = EntityEquipment {
+                entity_id,
+                slot: i32::from(equipment),
+                item: Some(*item),
+            };
+            network.send(packet);
+        }
     }
 }
 
-/// System for sending the Set Slot packet
+/// System for queueing the Set Slot packet
 /// when a player's inventory is updated.
+///
+/// Packets are sent at the end of the tick by
+/// `send_queued_set_slots`, so repeated updates to
+/// a slot within one tick result in a single packet.
 #[fecs::event_handler]
-pub fn on_inventory_update_send_set_slot(event: &InventoryUpdateEvent, world: &mut World) {
+pub fn on_inventory_update_send_set_slot(
+    event: &InventoryUpdateEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
     let inv = world.get::<Inventory>(event.player);
-    let network = world.get::<Network>(event.player);
 
     for slot in &event.slots {
+        // `try_item_at` returns `None` rather than panicking
+        // for slots outside of the inventory.
         let packet = SetSlot {
             window_id: 0,
             slot: *slot as i16,
-            slot_data: inv.item_at(*slot as usize).cloned(),
+            slot_data: inv.try_item_at(*slot).cloned(),
         };
 
-        network.send(packet);
+        game.queue_set_slot(event.player, packet);
     }
 }
 
 /// Returns whether the given update to an inventory
 /// is an equipment update.
+///
+/// The slots considered are the hotbar slot of the held
+/// item (36-44), the armor slots (5-8) and the offhand
+/// slot (45). Any other index, however large, is not
+/// an equipment update.
 fn is_equipment_update(held_item: SlotIndex, slot: SlotIndex) -> Result<Equipment, ()> {
-    if slot >= SLOT_HOTBAR_OFFSET && slot - SLOT_HOTBAR_OFFSET == held_item {
-        Ok(Equipment::MainHand)
-    } else if let Some(equipment) = Equipment::from_slot_index(slot) {
-        Ok(equipment)
-    } else {
-        Err(())
+    match classify(slot) {
+        Some(InventorySlot::Hotbar(index)) if index as SlotIndex == held_item => {
+            Ok(Equipment::MainHand)
+        }
+        Some(InventorySlot::Armor(equipment)) => Ok(equipment),
+        Some(InventorySlot::OffHand) => Ok(Equipment::OffHand),
+        _ => Err(()),
     }
 }
 
@@ -116,9 +167,11 @@ fn is_equipment_update(held_item: SlotIndex, slot: SlotIndex) -> Result<Equipmen
 mod tests {
     use super::*;
     use feather_core::inventory::{
-        SLOT_ARMOR_CHEST, SLOT_ARMOR_FEET, SLOT_ARMOR_HEAD, SLOT_ARMOR_LEGS, SLOT_INVENTORY_OFFSET,
+        SLOT_ARMOR_CHEST, SLOT_ARMOR_FEET, SLOT_ARMOR_HEAD, SLOT_ARMOR_LEGS, SLOT_HOTBAR_OFFSET,
+        SLOT_INVENTORY_OFFSET,
     };
-    use feather_core::items::{Item, ItemStack};
+    use feather_core::items::Item;
+    use feather_server_types::send_queued_set_slots;
     use feather_test_framework::Test;
     use smallvec::smallvec;
 
@@ -148,7 +201,7 @@ mod tests {
         let packet = test.sent::<EntityEquipment>(player2).unwrap();
         assert_eq!(packet.entity_id, test.id(player1));
         assert_eq!(packet.item, Some(stack));
-        assert_eq!(packet.slot, Equipment::MainHand.to_i32().unwrap());
+        assert_eq!(packet.slot, i32::from(Equipment::MainHand));
 
         assert!(test.sent::<EntityEquipment>(player3).is_none());
         assert!(test.sent::<EntityEquipment>(player1).is_none());
@@ -172,6 +225,113 @@ mod tests {
         }
     }
 
+    #[fecs::event_handler]
+    fn disguise_equipment(event: &EntityEquipmentBroadcastEvent) {
+        event.set_item(Some(ItemStack::new(Item::CarvedPumpkin, 1)));
+    }
+
+    #[fecs::event_handler]
+    fn hide_equipment(event: &EntityEquipmentBroadcastEvent) {
+        event.cancel();
+    }
+
+    #[test]
+    fn broadcast_equipment_rewritten_by_handler() {
+        let mut test = Test::new().with_event_handler(disguise_equipment);
+
+        let (packet, _) = test.broadcast_routine::<EntityEquipment, _, _, _>(
+            |test, player1, _| {
+                test.world
+                    .get_mut::<Inventory>(player1)
+                    .set_item_at(SLOT_ARMOR_HEAD, ItemStack::new(Item::DiamondHelmet, 1));
+                InventoryUpdateEvent {
+                    slots: smallvec![SLOT_ARMOR_HEAD],
+                    player: player1,
+                }
+            },
+            on_inventory_update_broadcast_equipment_update,
+            false,
+        );
+
+        assert_eq!(packet.slot, i32::from(Equipment::Helmet));
+        assert_eq!(packet.item, Some(ItemStack::new(Item::CarvedPumpkin, 1)));
+    }
+
+    #[test]
+    fn broadcast_equipment_cancelled_by_handler() {
+        let mut test = Test::new().with_event_handler(hide_equipment);
+
+        let player1 = test.player("", position!(0.0, 100.0, 0.0));
+        let player2 = test.player("", position!(1.0, 100.0, 0.0));
+
+        test.world
+            .get_mut::<Inventory>(player1)
+            .set_item_at(SLOT_ARMOR_HEAD, ItemStack::new(Item::DiamondHelmet, 1));
+
+        test.handle(
+            InventoryUpdateEvent {
+                slots: smallvec![SLOT_ARMOR_HEAD],
+                player: player1,
+            },
+            on_inventory_update_broadcast_equipment_update,
+        );
+
+        assert!(test.sent::<EntityEquipment>(player2).is_none());
+    }
+
+    #[test]
+    fn broadcast_held_item_change() {
+        let mut test = Test::new();
+
+        let stack = ItemStack::new(Item::DiamondSword, 1);
+        let (packet, player) = test.broadcast_routine::<EntityEquipment, _, _, _>(
+            |test, player1, _| {
+                test.world
+                    .get_mut::<Inventory>(player1)
+                    .set_item_at(SLOT_HOTBAR_OFFSET + 3, stack);
+                test.world.get_mut::<HeldItem>(player1).0 = 3;
+                HeldItemChangeEvent {
+                    player: player1,
+                    old_slot: 0,
+                    new_slot: 3,
+                }
+            },
+            on_held_item_change_broadcast_equipment_update,
+            false,
+        );
+
+        assert_eq!(packet.entity_id, test.id(player));
+        assert_eq!(packet.slot, i32::from(Equipment::MainHand));
+        assert_eq!(packet.item, Some(stack));
+    }
+
+    #[test]
+    fn held_item_change_to_same_item_not_broadcast() {
+        let mut test = Test::new();
+
+        let player1 = test.player("", position!(0.0, 100.0, 0.0));
+        let player2 = test.player("", position!(1.0, 100.0, 0.0));
+
+        let stack = ItemStack::new(Item::Stone, 64);
+        {
+            let mut inv = test.world.get_mut::<Inventory>(player1);
+            inv.set_item_at(SLOT_HOTBAR_OFFSET, stack);
+            inv.set_item_at(SLOT_HOTBAR_OFFSET + 1, stack);
+        }
+        test.world.get_mut::<HeldItem>(player1).0 = 1;
+
+        test.handle(
+            HeldItemChangeEvent {
+                player: player1,
+                old_slot: 0,
+                new_slot: 1,
+            },
+            on_held_item_change_broadcast_equipment_update,
+        );
+
+        assert!(test.sent::<EntityEquipment>(player2).is_none());
+    }
+
     #[test]
     fn send_equipment_on_send() {
         let mut test = Test::new();
@@ -192,11 +352,49 @@ mod tests {
             false,
         );
 
-        assert_eq!(packet.slot, Equipment::MainHand.to_i32().unwrap());
+        assert_eq!(packet.slot, i32::from(Equipment::MainHand));
         assert_eq!(packet.entity_id, test.id(player));
         assert_eq!(packet.item, Some(stack));
     }
 
+    #[test]
+    fn send_current_equipment_to_player_entering_range() {
+        let mut test = Test::new();
+
+        let player1 = test.player("", position!(0.0, 100.0, 0.0));
+        let player2 = test.player("", position!(1000.0, 100.0, 0.0));
+
+        let helmet = ItemStack::new(Item::IronHelmet, 1);
+        test.world
+            .get_mut::<Inventory>(player1)
+            .set_item_at(SLOT_ARMOR_HEAD, helmet);
+        test.handle(
+            InventoryUpdateEvent {
+                slots: smallvec![SLOT_ARMOR_HEAD],
+                player: player1,
+            },
+            on_inventory_update_broadcast_equipment_update,
+        );
+
+        // Out of range at the time of the change.
+        assert!(test.sent::<EntityEquipment>(player2).is_none());
+
+        // Player 2 approaches, so player 1 is sent to them.
+        test.position(player2, position!(1.0, 100.0, 0.0));
+        test.handle(
+            EntitySendEvent {
+                entity: player1,
+                client: player2,
+            },
+            on_entity_send_send_equipment,
+        );
+
+        let packet = test.sent::<EntityEquipment>(player2).unwrap();
+        assert_eq!(packet.entity_id, test.id(player1));
+        assert_eq!(packet.slot, i32::from(Equipment::Helmet));
+        assert_eq!(packet.item, Some(helmet));
+    }
+
     #[test]
     fn send_set_slot() {
         let mut test = Test::new();
@@ -218,6 +416,7 @@ mod tests {
             },
             on_inventory_update_send_set_slot,
         );
+        test.run(send_queued_set_slots);
 
         let packet = test.sent::<SetSlot>(player1).unwrap();
         assert_eq!(packet.slot, slot as i16);
@@ -226,6 +425,87 @@ mod tests {
         assert!(test.sent::<SetSlot>(player2).is_none());
     }
 
+    #[test]
+    fn send_set_slot_out_of_range_slot_does_not_panic() {
+        let mut test = Test::new();
+
+        let player = test.player("", position!(0.0, 64.0, 0.0));
+
+        test.handle(
+            InventoryUpdateEvent {
+                slots: smallvec![1000],
+                player,
+            },
+            on_inventory_update_send_set_slot,
+        );
+        test.run(send_queued_set_slots);
+
+        let packet = test.sent::<SetSlot>(player).unwrap();
+        assert_eq!(packet.slot, 1000);
+        assert_eq!(packet.slot_data, None);
+    }
+
+    #[test]
+    fn send_set_slot_coalesces_updates_within_tick() {
+        let mut test = Test::new();
+
+        let slot = SLOT_INVENTORY_OFFSET;
+        let player = test.player("", position!(0.0, 64.0, 0.0));
+
+        for amount in 1..=2 {
+            test.world
+                .get_mut::<Inventory>(player)
+                .set_item_at(slot, ItemStack::new(Item::Stone, amount));
+            test.handle(
+                InventoryUpdateEvent {
+                    slots: smallvec![slot],
+                    player,
+                },
+                on_inventory_update_send_set_slot,
+            );
+        }
+
+        // Nothing is sent until the end of the tick.
+        assert!(test.sent_all::<SetSlot>(player).is_empty());
+        test.run(send_queued_set_slots);
+
+        let packets = test.sent_all::<SetSlot>(player);
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].slot, slot as i16);
+        assert_eq!(packets[0].slot_data, Some(ItemStack::new(Item::Stone, 2)));
+    }
+
+    #[test]
+    fn send_set_slot_keeps_queue_order() {
+        let mut test = Test::new();
+
+        let player = test.player("", position!(0.0, 64.0, 0.0));
+        let slots = [
+            SLOT_INVENTORY_OFFSET + 5,
+            SLOT_INVENTORY_OFFSET + 3,
+            SLOT_INVENTORY_OFFSET + 5,
+            SLOT_INVENTORY_OFFSET + 8,
+        ];
+
+        for slot in &slots {
+            test.handle(
+                InventoryUpdateEvent {
+                    slots: smallvec![*slot],
+                    player,
+                },
+                on_inventory_update_send_set_slot,
+            );
+        }
+        test.run(send_queued_set_slots);
+
+        let sent = test
+            .sent_all::<SetSlot>(player)
+            .into_iter()
+            .map(|packet| packet.slot as SlotIndex)
+            .collect::<Vec<_>>();
+        assert_eq!(sent, vec![slots[0], slots[1], slots[3]]);
+    }
+
     #[test]
     fn test_is_equipment_update() {
         let results = vec![
@@ -237,6 +517,8 @@ mod tests {
             (0, SLOT_ARMOR_LEGS, Ok(Equipment::Leggings)),
             (0, SLOT_ARMOR_HEAD, Ok(Equipment::Helmet)),
             (0, 10000, Err(())),
+            (0, usize::MAX, Err(())),
+            (8, SLOT_HOTBAR_OFFSET + 7, Err(())),
         ];
 
         for (held_item, slot, expected) in results {
@@ -8,8 +8,8 @@ mod join;
 mod packet_handlers;
 mod view;
 
-use feather_core::inventory::{Inventory, InventoryType};
-use feather_core::items::{Item, ItemStack};
+use feather_core::anvil::player::AnvilInventory;
+use feather_core::inventory::{self, Inventory};
 use feather_core::network::packets::{PlayerInfo, PlayerInfoAction, SpawnPlayer};
 use feather_core::network::Packet;
 use feather_core::text::Text;
@@ -30,7 +30,7 @@ pub use packet_handlers::*;
 use std::sync::atomic::Ordering;
 pub use view::*;
 
-pub const PLAYER_INVENTORY_SIZE: u32 = 46;
+pub const PLAYER_INVENTORY_SIZE: u32 = inventory::PLAYER_INVENTORY_SIZE as u32;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ItemTimedUse {
@@ -71,19 +71,8 @@ pub fn create(game: &mut Game, world: &mut World, info: NewClientInfo) -> Entity
         .add(entity, Gamemode::from_id(info.data.gamemode as u8))
         .unwrap();
 
-    let items = info.data.inventory.iter().map(|slot| {
-        (
-            slot.slot as usize,
-            ItemStack::new(
-                Item::from_identifier(&slot.item).unwrap_or(Item::Air),
-                slot.count as u8,
-            ),
-        )
-    });
-    let slots = info.data.inventory.iter().map(|slot| slot.slot as usize);
-
-    let mut inventory = Inventory::new(InventoryType::Player, PLAYER_INVENTORY_SIZE);
-    items.for_each(|(index, item)| inventory.set_item_at(index, item));
+    let inventory = Inventory::from_anvil_slots(&info.data.inventory);
+    let slots = inventory.iter().map(|(index, _)| index).collect();
 
     world.add(entity, inventory).unwrap();
     world.add(entity, HeldItem(0)).unwrap(); // todo: load from player data
@@ -96,7 +85,7 @@ pub fn create(game: &mut Game, world: &mut World, info: NewClientInfo) -> Entity
     game.handle(
         world,
         InventoryUpdateEvent {
-            slots: slots.collect(),
+            slots,
             player: entity,
         },
     );
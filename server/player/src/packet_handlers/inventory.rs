@@ -2,10 +2,12 @@
 //! This currently includes Creative Inventory Action and Held Item Change.
 
 use crate::IteratorExt;
-use feather_core::inventory::{Inventory, HOTBAR_SIZE, SLOT_HOTBAR_OFFSET};
+use feather_core::inventory::{Inventory, HOTBAR_SIZE};
 use feather_core::network::packets::{CreativeInventoryAction, HeldItemChangeServerbound};
 use feather_core::util::Gamemode;
-use feather_server_types::{Game, HeldItem, InventoryUpdateEvent, ItemDropEvent, PacketBuffers};
+use feather_server_types::{
+    Game, HeldItem, HeldItemChangeEvent, InventoryUpdateEvent, ItemDropEvent, PacketBuffers,
+};
 use fecs::World;
 use std::sync::Arc;
 
@@ -97,12 +99,14 @@ pub fn handle_held_item_change(
         }
 
         let mut held_item = world.get_mut::<HeldItem>(player);
+        let old_slot = held_item.0;
         held_item.0 = packet.slot as usize;
 
         // Trigger event
-        let event = InventoryUpdateEvent {
-            slots: std::iter::once(held_item.0 as usize + SLOT_HOTBAR_OFFSET).collect(),
+        let event = HeldItemChangeEvent {
             player,
+            old_slot,
+            new_slot: held_item.0,
         };
         drop(held_item);
         game.handle(world, event);
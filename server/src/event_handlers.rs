@@ -56,11 +56,14 @@ pub fn build_event_handlers() -> EventHandlers {
         on_chunk_cross_update_chunks,
         on_chunk_cross_update_chunk_entities,
         on_chunk_cross_update_entities,
+        on_chunk_cross_destroy_for_former_trackers,
+        on_chunk_cross_send_to_new_trackers,
 
         on_chunk_send_join_player,
 
         on_inventory_update_send_set_slot,
         on_inventory_update_broadcast_equipment_update,
+        on_held_item_change_broadcast_equipment_update,
 
         on_player_animation_broadcast_animation,
 
@@ -62,6 +62,7 @@ pub async fn init(
         rng: Default::default(),
         bump: Default::default(),
         player_count: Arc::new(Default::default()),
+        queued_set_slots: Default::default(),
     };
     let packet_buffers = Arc::new(PacketBuffers::new());
 
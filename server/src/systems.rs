@@ -34,6 +34,7 @@ pub fn build_executor() -> Executor {
         .with(entity::broadcast_velocity)
         .with(entity::falling_block::spawn_falling_blocks)
         .with(chunk_logic::chunk_save)
+        .with(game::send_queued_set_slots)
         .with(game::reset_bump_allocators)
         .with(game::increment_tick_count)
         .with(util::increment_time)
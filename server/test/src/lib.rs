@@ -5,4 +5,4 @@
 
 mod unit;
 
-pub use unit::Test;
+pub use unit::{Test, WindowPackets};
@@ -2,6 +2,7 @@
 
 use feather_core::anvil::entity::BaseEntityData;
 use feather_core::anvil::player::PlayerData;
+use feather_core::network::packets::{SetSlot, WindowItems};
 use feather_core::network::{cast_packet, Packet};
 use feather_core::util::{vec3, Position};
 use feather_server_chunk::{
@@ -10,8 +11,8 @@ use feather_server_chunk::{
 use feather_server_network::NewClientInfo;
 use feather_server_player::on_chunk_cross_update_chunks;
 use feather_server_types::{
-    ChunkCrossEvent, ChunkHolder, Game, Name, NetworkId, RunningTasks, ServerToWorkerMessage, Uuid,
-    WorkerToServerMessage,
+    send_queued_set_slots, ChunkCrossEvent, ChunkHolder, Game, Name, NetworkId, RunningTasks,
+    ServerToWorkerMessage, Uuid, WorkerToServerMessage,
 };
 use feather_server_util::on_chunk_cross_update_chunk_entities;
 use fecs::{
@@ -34,6 +35,14 @@ struct TrackedPlayer {
     disconnected: bool,
 }
 
+/// Packets sent to a player for a single window,
+/// as captured by `Test::window_routine`.
+#[derive(Default)]
+pub struct WindowPackets {
+    pub set_slot: Vec<SetSlot>,
+    pub window_items: Vec<WindowItems>,
+}
+
 pub struct Test {
     pub game: Game,
     pub world: World,
@@ -91,6 +100,7 @@ impl Test {
             rng: Default::default(),
             bump: Default::default(),
             player_count: Arc::new(Default::default()),
+            queued_set_slots: Default::default(),
         };
         resources.insert(cworker_handle);
 
@@ -107,6 +117,21 @@ impl Test {
         self
     }
 
+    /// Registers an event handler which is invoked when
+    /// events are triggered through `Game::handle`.
+    pub fn with_event_handler(mut self, mut handler: impl RawEventHandler + 'static) -> Self {
+        handler.set_up(
+            Arc::get_mut(&mut self.game.resources).expect("resources already borrowed"),
+            &mut self.world,
+        );
+
+        let event_handlers =
+            Arc::get_mut(&mut self.game.event_handlers).expect("event handlers already borrowed");
+        *event_handlers = std::mem::replace(event_handlers, EventHandlers::new()).with(handler);
+
+        self
+    }
+
     /// Runs a system for this `Test`.
     pub fn run(&mut self, mut system: impl RawSystem) -> &mut Self {
         system.set_up(
@@ -179,6 +204,55 @@ impl Test {
         (self.sent::<P>(player2).unwrap(), player1)
     }
 
+    /// Routine for testing window packets. Creates a player,
+    /// builds an event for the player and the given window
+    /// ID, and handles it. Returns the `SetSlot` and `WindowItems`
+    /// packets sent to the player for that window.
+    ///
+    /// Set Slot packets queued by the handler are flushed by
+    /// running `send_queued_set_slots`, as at the end of a tick.
+    ///
+    /// Only window 0, the player's own inventory, is supported:
+    /// it is always open, while the server does not yet track
+    /// other open windows. Passing any other ID panics.
+    pub fn window_routine<E, F, H>(
+        &mut self,
+        window_id: u8,
+        event: F,
+        handler: H,
+    ) -> (WindowPackets, Entity)
+    where
+        E: Event,
+        F: FnOnce(&mut Self, Entity, u8) -> E,
+        H: RawEventHandler<Event = E>,
+    {
+        use feather_core::position;
+        assert_eq!(
+            window_id, 0,
+            "only the player inventory window is supported"
+        );
+        let player = self.player("", position!(0.0, 64.0, 0.0));
+
+        let event = event(self, player, window_id);
+        self.handle(event, handler);
+        self.run(send_queued_set_slots);
+
+        let packets = WindowPackets {
+            set_slot: self
+                .sent_all::<SetSlot>(player)
+                .into_iter()
+                .filter(|packet| packet.window_id as u8 == window_id)
+                .collect(),
+            window_items: self
+                .sent_all::<WindowItems>(player)
+                .into_iter()
+                .filter(|packet| packet.window_id == window_id)
+                .collect(),
+        };
+
+        (packets, player)
+    }
+
     /// Creates a dummy player with the given name.
     pub fn player(&mut self, name: impl Into<Cow<'static, str>>, position: Position) -> Entity {
         let mut name = name.into();
@@ -261,6 +335,23 @@ impl Test {
         Self::remove_player_buffered_packet(tracked)
     }
 
+    /// Returns all packets of type `P` sent to `player`,
+    /// in the order they were sent.
+    pub fn sent_all<P>(&mut self, player: Entity) -> Vec<P>
+    where
+        P: Packet,
+    {
+        let tracked = self.tracked_player(player);
+
+        Self::update_player(tracked);
+
+        let mut packets = vec![];
+        while let Some(packet) = Self::remove_player_buffered_packet(tracked) {
+            packets.push(packet);
+        }
+        packets
+    }
+
     fn remove_player_buffered_packet<P>(player: &mut TrackedPlayer) -> Option<P>
     where
         P: Packet,
@@ -361,3 +452,72 @@ impl ChunkWorkerTester {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::position;
+    use feather_server_types::Network;
+
+    #[test]
+    fn sent_all() {
+        let mut test = Test::new();
+        let player = test.player("", position!(0.0, 64.0, 0.0));
+
+        for slot in 0..2 {
+            test.world.get::<Network>(player).send(SetSlot {
+                window_id: 0,
+                slot,
+                slot_data: None,
+            });
+        }
+
+        let packets = test.sent_all::<SetSlot>(player);
+        assert_eq!(
+            packets.iter().map(|packet| packet.slot).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert!(test.sent_all::<SetSlot>(player).is_empty());
+    }
+
+    struct SendSlots {
+        player: Entity,
+    }
+
+    #[fecs::event_handler]
+    fn send_slots(event: &SendSlots, game: &mut Game, world: &mut World) {
+        // Sent to another window, so not captured.
+        world.get::<Network>(event.player).send(SetSlot {
+            window_id: 1,
+            slot: 0,
+            slot_data: None,
+        });
+        game.queue_set_slot(
+            event.player,
+            SetSlot {
+                window_id: 0,
+                slot: 36,
+                slot_data: None,
+            },
+        );
+    }
+
+    #[test]
+    fn window_routine() {
+        let mut test = Test::new();
+
+        let (packets, _) = test.window_routine(0, |_, player, _| SendSlots { player }, send_slots);
+
+        assert_eq!(packets.set_slot.len(), 1);
+        assert_eq!(packets.set_slot[0].window_id, 0);
+        assert_eq!(packets.set_slot[0].slot, 36);
+        assert!(packets.window_items.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn window_routine_other_window() {
+        let mut test = Test::new();
+        test.window_routine(1, |_, player, _| SendSlots { player }, send_slots);
+    }
+}
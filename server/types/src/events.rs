@@ -1,10 +1,12 @@
 use crate::Weather;
 use feather_core::blocks::BlockId;
-use feather_core::inventory::SlotIndex;
+use feather_core::inventory::{Equipment, SlotIndex};
 use feather_core::items::ItemStack;
 use feather_core::util::{BlockPosition, ChunkPosition, ClientboundAnimation, Position};
 use fecs::Entity;
+use parking_lot::Mutex;
 use smallvec::SmallVec;
+use std::sync::Arc;
 
 #[derive(Copy, Clone, Debug)]
 pub struct BlockUpdateEvent {
@@ -93,9 +95,6 @@ pub struct ItemCollectEvent {
 
 /// Event which is triggered when a player
 /// updates their inventory.
-///
-/// This event could also be triggered when the player
-/// changes their held item.
 #[derive(Debug, Clone)]
 pub struct InventoryUpdateEvent {
     /// The slot(s) affected by the update.
@@ -107,6 +106,78 @@ pub struct InventoryUpdateEvent {
     pub player: Entity,
 }
 
+/// Event triggered when a player changes their
+/// selected hotbar slot.
+///
+/// The inventory itself is unchanged, so no
+/// `InventoryUpdateEvent` is triggered.
+#[derive(Copy, Clone, Debug)]
+pub struct HeldItemChangeEvent {
+    /// The player who changed their held item.
+    pub player: Entity,
+    /// The previously selected hotbar slot (0-8).
+    pub old_slot: SlotIndex,
+    /// The newly selected hotbar slot (0-8).
+    pub new_slot: SlotIndex,
+}
+
+/// Event triggered before an entity's equipment
+/// is broadcast to nearby players.
+///
+/// Handlers may replace the broadcasted item (e.g. for disguises
+/// or cosmetic armor) or cancel the broadcast altogether. The
+/// entity's actual inventory is not affected.
+///
+/// Clones of this event share their state, so the triggering
+/// system can observe changes made by handlers.
+#[derive(Clone, Debug)]
+pub struct EntityEquipmentBroadcastEvent {
+    /// The entity whose equipment is being broadcast.
+    pub entity: Entity,
+    /// The equipment slot being broadcast.
+    pub equipment: Equipment,
+    state: Arc<Mutex<EquipmentBroadcastState>>,
+}
+
+#[derive(Debug)]
+struct EquipmentBroadcastState {
+    item: Option<ItemStack>,
+    cancelled: bool,
+}
+
+impl EntityEquipmentBroadcastEvent {
+    pub fn new(entity: Entity, equipment: Equipment, item: Option<ItemStack>) -> Self {
+        Self {
+            entity,
+            equipment,
+            state: Arc::new(Mutex::new(EquipmentBroadcastState {
+                item,
+                cancelled: false,
+            })),
+        }
+    }
+
+    /// Returns the item which will be broadcast.
+    pub fn item(&self) -> Option<ItemStack> {
+        self.state.lock().item
+    }
+
+    /// Sets the item which will be broadcast.
+    pub fn set_item(&self, item: Option<ItemStack>) {
+        self.state.lock().item = item;
+    }
+
+    /// Cancels the broadcast.
+    pub fn cancel(&self) {
+        self.state.lock().cancelled = true;
+    }
+
+    /// Returns whether the broadcast has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.lock().cancelled
+    }
+}
+
 /// Event triggered when an entity is created.
 #[derive(Copy, Clone, Debug)]
 pub struct EntitySpawnEvent {
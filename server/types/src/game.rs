@@ -1,15 +1,21 @@
 use crate::task::RunningTasks;
-use crate::{BlockUpdateCause, BlockUpdateEvent, EntityDespawnEvent, Name, PlayerLeaveEvent};
+use crate::{
+    BlockUpdateCause, BlockUpdateEvent, EntityDespawnEvent, Name, NetworkId, PlayerLeaveEvent,
+    SoundCategory,
+};
 use crate::{Network, ServerToWorkerMessage};
 use ahash::AHashMap;
 use bumpalo::Bump;
 use feather_core::anvil::level::LevelData;
 use feather_core::blocks::BlockId;
 use feather_core::chunk_map::ChunkMap;
+use feather_core::items::ItemStack;
+use feather_core::network::packets::{DestroyEntities, NamedSoundEffect, SetSlot};
 use feather_core::network::Packet;
 use feather_core::util::{BlockPosition, ChunkPosition, Position};
 use feather_server_config::Config;
 use fecs::{Entity, Event, EventHandlers, IntoQuery, OwnedResources, Read, RefResources, World};
+use indexmap::IndexMap;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use smallvec::SmallVec;
@@ -53,6 +59,10 @@ pub struct Game {
     pub bump: CachedThreadLocal<Bump>,
     /// The server player count.
     pub player_count: Arc<AtomicU32>,
+    /// Set Slot updates queued this tick, keyed on
+    /// `(player, window_id, slot)` and kept in the order
+    /// they were first queued. Flushed at the end of the tick.
+    pub queued_set_slots: IndexMap<(Entity, i8, i16), Option<ItemStack>>,
 }
 
 impl Game {
@@ -193,6 +203,34 @@ impl Game {
         }
     }
 
+    /// Broadcasts a packet to all players tracking a given entity,
+    /// i.e. those who have a hold on the entity's chunk.
+    ///
+    /// Unlike `broadcast_entity_update`, this makes no assumption
+    /// about the packet, so it can be used for sounds, particles
+    /// and animations tied to an entity.
+    pub fn broadcast_to_tracking<P: Packet>(
+        &self,
+        world: &World,
+        packet: P,
+        entity: Entity,
+        exclude: Option<Entity>,
+    ) {
+        self.broadcast_to_tracking_boxed(world, Box::new(packet), entity, exclude);
+    }
+
+    /// Broadcasts a boxed packet to all players tracking a given entity.
+    pub fn broadcast_to_tracking_boxed(
+        &self,
+        world: &World,
+        packet: Box<dyn Packet>,
+        entity: Entity,
+        exclude: Option<Entity>,
+    ) {
+        let entity_chunk = world.get::<Position>(entity).chunk();
+        self.broadcast_chunk_update_boxed(world, packet, entity_chunk, exclude);
+    }
+
     /// Broadcasts a packet to all players able to see a given entity.
     pub fn broadcast_entity_update(
         &self,
@@ -201,7 +239,7 @@ impl Game {
         entity: Entity,
         neq: Option<Entity>,
     ) {
-        self.broadcast_entity_update_boxed(world, Box::new(packet), entity, neq);
+        self.broadcast_to_tracking(world, packet, entity, neq);
     }
 
     /// Broadcasts a boxed packet to all players able to see a given entity.
@@ -212,9 +250,68 @@ impl Game {
         entity: Entity,
         neq: Option<Entity>,
     ) {
-        // Send the packet to all players who have a hold on the entity's chunk.
-        let entity_chunk = world.get::<Position>(entity).chunk();
-        self.broadcast_chunk_update_boxed(world, packet, entity_chunk, neq);
+        self.broadcast_to_tracking_boxed(world, packet, entity, neq);
+    }
+
+    /// Plays a sound at the given position for all players
+    /// able to see its chunk.
+    pub fn broadcast_sound(
+        &self,
+        world: &World,
+        position: Position,
+        sound: &str,
+        category: SoundCategory,
+        volume: f32,
+        pitch: f32,
+    ) {
+        // The protocol uses fixed-point coordinates with three fractional bits.
+        let packet = NamedSoundEffect {
+            sound_name: sound.to_owned(),
+            sound_category: category as i32,
+            effect_pos_x: (position.x * 8.0) as i32,
+            effect_pos_y: (position.y * 8.0) as i32,
+            effect_pos_z: (position.z * 8.0) as i32,
+            volume,
+            pitch,
+        };
+        self.broadcast_chunk_update(world, packet, position.chunk(), None);
+    }
+
+    /// Tells all players tracking a given entity to despawn it.
+    pub fn broadcast_destroy_entity(&self, world: &World, entity: Entity, exclude: Option<Entity>) {
+        let packet = DestroyEntities {
+            entity_ids: vec![world.get::<NetworkId>(entity).0],
+        };
+        self.broadcast_to_tracking(world, packet, entity, exclude);
+    }
+
+    /// Queues a Set Slot packet to be sent to `player` at the
+    /// end of the tick. If the same slot is updated several times
+    /// in one tick, only its final contents are sent, in the
+    /// position of the first update.
+    ///
+    /// Queued packets are sent after any packet sent directly
+    /// during the tick, so a packet which must follow this
+    /// update should be queued or sent after the flush.
+    pub fn queue_set_slot(&mut self, player: Entity, packet: SetSlot) {
+        self.queued_set_slots
+            .insert((player, packet.window_id, packet.slot), packet.slot_data);
+    }
+
+    /// Sends all Set Slot packets queued this tick.
+    ///
+    /// Updates for players which have since been removed are dropped.
+    pub fn flush_set_slots(&mut self, world: &World) {
+        let queued = std::mem::take(&mut self.queued_set_slots);
+        for ((player, window_id, slot), slot_data) in queued {
+            if let Some(network) = world.try_get::<Network>(player) {
+                network.send(SetSlot {
+                    window_id,
+                    slot,
+                    slot_data,
+                });
+            }
+        }
     }
 }
 
@@ -313,6 +410,11 @@ pub fn reset_bump_allocators(game: &mut Game) {
     game.bump.iter_mut().for_each(Bump::reset);
 }
 
+#[fecs::system]
+pub fn send_queued_set_slots(game: &mut Game, world: &mut World) {
+    game.flush_set_slots(world);
+}
+
 #[fecs::system]
 pub fn increment_tick_count(game: &mut Game) {
     game.tick_count += 1;
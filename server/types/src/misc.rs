@@ -1,5 +1,6 @@
 use feather_core::anvil::entity::{EntityData, EntityDataKind};
 use fecs::EntityBuilder;
+use rand::Rng;
 
 pub type BumpVec<'bump, T> = bumpalo::collections::Vec<'bump, T>;
 
@@ -13,6 +14,16 @@ impl<F> EntityLoaderFn for F where
 {
 }
 
+pub trait EntityPostLoadFn:
+    Fn(EntityBuilder) -> anyhow::Result<EntityBuilder> + Send + Sync + 'static
+{
+}
+
+impl<F> EntityPostLoadFn for F where
+    F: Fn(EntityBuilder) -> anyhow::Result<EntityBuilder> + Send + Sync + 'static
+{
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Weather {
     Clear,
@@ -20,6 +31,50 @@ pub enum Weather {
     Thunder,
 }
 
+/// Chance that clear weather is followed by a thunderstorm
+/// rather than by rain.
+pub const CLEAR_TO_THUNDER_CHANCE: f64 = 0.1;
+/// Chance that rain turns into a thunderstorm
+/// rather than clearing up.
+pub const RAIN_TO_THUNDER_CHANCE: f64 = 0.25;
+/// Chance that a thunderstorm calms down to rain
+/// rather than clearing up.
+pub const THUNDER_TO_RAIN_CHANCE: f64 = 0.3;
+
+impl Weather {
+    /// Randomly picks the weather which follows this one,
+    /// using the `*_CHANCE` transition probabilities.
+    ///
+    /// The returned weather always differs from `self`.
+    pub fn next(self, rng: &mut impl Rng) -> Weather {
+        match self {
+            Weather::Clear if rng.gen_bool(CLEAR_TO_THUNDER_CHANCE) => Weather::Thunder,
+            Weather::Clear => Weather::Rain,
+            Weather::Rain if rng.gen_bool(RAIN_TO_THUNDER_CHANCE) => Weather::Thunder,
+            Weather::Rain => Weather::Clear,
+            Weather::Thunder if rng.gen_bool(THUNDER_TO_RAIN_CHANCE) => Weather::Rain,
+            Weather::Thunder => Weather::Clear,
+        }
+    }
+}
+
+/// A category of sounds, whose volume clients
+/// can adjust separately. Variants are listed
+/// in the order of their protocol IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundCategory {
+    Master,
+    Music,
+    Records,
+    Weather,
+    Blocks,
+    Hostile,
+    Neutral,
+    Players,
+    Ambient,
+    Voice,
+}
+
 /// A registration for a function to convert an `EntityData`
 /// to an `EntityBuilder` for spawning into the world. The
 /// registration must provide the `EntityDataKind` it handles
@@ -39,3 +94,88 @@ impl EntityLoaderRegistration {
 }
 
 inventory::collect!(EntityLoaderRegistration);
+
+/// A registration for a function which is run on every
+/// `EntityBuilder` produced by an entity loader, regardless
+/// of its `EntityDataKind`. Used to attach components common
+/// to all loaded entities in one place.
+pub struct EntityPostLoadRegistration {
+    /// The hook function.
+    pub f: &'static dyn EntityPostLoadFn,
+}
+
+impl EntityPostLoadRegistration {
+    pub fn new(f: &'static dyn EntityPostLoadFn) -> Self {
+        Self { f }
+    }
+}
+
+inventory::collect!(EntityPostLoadRegistration);
+
+/// Returns the kinds of `EntityData` for which a loader
+/// has been registered, i.e. the entities the server
+/// is able to spawn.
+pub fn registered_entity_kinds() -> Vec<EntityDataKind> {
+    inventory::iter::<EntityLoaderRegistration>
+        .into_iter()
+        .map(|registration| registration.kind)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn load(_data: EntityData) -> anyhow::Result<EntityBuilder> {
+        Ok(EntityBuilder::new())
+    }
+
+    inventory::submit! {
+        EntityLoaderRegistration::new(EntityDataKind::Cow, &load)
+    }
+
+    inventory::submit! {
+        EntityLoaderRegistration::new(EntityDataKind::Squid, &load)
+    }
+
+    #[test]
+    fn test_registered_entity_kinds() {
+        let kinds = registered_entity_kinds();
+        assert!(kinds.contains(&EntityDataKind::Cow));
+        assert!(kinds.contains(&EntityDataKind::Squid));
+        assert!(!kinds.contains(&EntityDataKind::Pig));
+    }
+
+    #[test]
+    fn test_weather_next() {
+        const SAMPLES: usize = 10_000;
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        for (from, to, chance) in &[
+            (Weather::Clear, Weather::Thunder, CLEAR_TO_THUNDER_CHANCE),
+            (Weather::Rain, Weather::Thunder, RAIN_TO_THUNDER_CHANCE),
+            (Weather::Thunder, Weather::Rain, THUNDER_TO_RAIN_CHANCE),
+        ] {
+            let mut hits = 0;
+            for _ in 0..SAMPLES {
+                let next = from.next(&mut rng);
+                assert_ne!(next, *from);
+                if next == *to {
+                    hits += 1;
+                }
+            }
+
+            let ratio = hits as f64 / SAMPLES as f64;
+            assert!(
+                (ratio - chance).abs() < 0.03,
+                "{:?} -> {:?} happened {} of the time, expected {}",
+                from,
+                to,
+                ratio,
+                chance
+            );
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use ahash::AHashMap;
 use feather_core::anvil::entity::{EntityData, EntityDataKind};
-use feather_server_types::{EntityLoaderFn, EntityLoaderRegistration};
+use feather_server_types::{
+    EntityLoaderFn, EntityLoaderRegistration, EntityPostLoadFn, EntityPostLoadRegistration,
+};
 use fecs::EntityBuilder;
 
 /// Stores state for loading entities.
@@ -8,6 +10,9 @@ pub struct EntityLoader {
     /// Map from `EntityDataKind` to functions
     /// to load entities of those kinds.
     loaders: AHashMap<EntityDataKind, &'static dyn EntityLoaderFn>,
+    /// Hooks run on every loaded entity, in
+    /// registration order.
+    post_load: Vec<&'static dyn EntityPostLoadFn>,
 }
 
 impl Default for EntityLoader {
@@ -23,16 +28,75 @@ impl EntityLoader {
             .into_iter()
             .map(|registration| (registration.kind, registration.f))
             .collect();
-        Self { loaders }
+        let post_load = inventory::iter::<EntityPostLoadRegistration>
+            .into_iter()
+            .map(|registration| registration.f)
+            .collect();
+        Self { loaders, post_load }
     }
 }
 
 impl EntityLoader {
     /// Converts an `EntityData` into an `EntityBuilder`
     /// ready for spawning in a `World`.
+    ///
+    /// Every registered `EntityPostLoadRegistration` hook
+    /// is then applied to the builder.
     pub fn load(&self, data: EntityData) -> Option<anyhow::Result<EntityBuilder>> {
-        self.loaders
-            .get(&EntityDataKind::from(&data))
-            .map(|loader| loader(data))
+        let loader = self.loaders.get(&EntityDataKind::from(&data))?;
+
+        Some(loader(data).and_then(|builder| {
+            self.post_load
+                .iter()
+                .try_fold(builder, |builder, hook| hook(builder))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::anvil::entity::{AnimalData, BaseEntityData};
+    use fecs::World;
+
+    struct Loaded;
+
+    fn load(_data: EntityData) -> anyhow::Result<EntityBuilder> {
+        Ok(EntityBuilder::new())
+    }
+
+    fn mark_loaded(builder: EntityBuilder) -> anyhow::Result<EntityBuilder> {
+        Ok(builder.with(Loaded))
+    }
+
+    inventory::submit! {
+        EntityLoaderRegistration::new(EntityDataKind::Cow, &load)
+    }
+
+    inventory::submit! {
+        EntityPostLoadRegistration::new(&mark_loaded)
+    }
+
+    #[test]
+    fn post_load_hooks() {
+        let loader = EntityLoader::new();
+        let mut world = World::new();
+
+        let data = EntityData::Cow(AnimalData {
+            base: BaseEntityData::default(),
+        });
+        let entity = loader
+            .load(data)
+            .unwrap()
+            .unwrap()
+            .build()
+            .spawn_in(&mut world);
+        assert!(world.has::<Loaded>(entity));
+
+        // No loader for pigs, so hooks don't run either.
+        let data = EntityData::Pig(AnimalData {
+            base: BaseEntityData::default(),
+        });
+        assert!(loader.load(data).is_none());
     }
 }
@@ -10,56 +10,61 @@ const TICKS_WEEK: i32 = TICKS_DAY * 7;
 
 #[allow(unused)]
 pub fn clear_weather(game: &mut Game) {
-    let duration = game
-        .rng()
-        .gen_range(TICKS_HALF_DAY, TICKS_WEEK + TICKS_HALF_DAY);
+    let duration = random_duration(game, Weather::Clear);
     set_weather(game, Weather::Clear, duration);
 }
 
 #[fecs::system]
 pub fn update_weather(game: &mut Game, world: &mut World) {
-    if game.level.clear_weather_time >= 0 {
-        game.level.clear_weather_time -= 1;
-        return;
-    }
-
     let from = get_weather(game);
 
-    game.level.rain_time -= 1;
-    let mut to = if game.level.rain_time <= 0 {
-        if game.level.raining {
-            Weather::Clear
-        } else {
-            Weather::Rain
+    if game.level.clear_weather_time > 0 {
+        // Clear weather lasts exactly `clear_weather_time` ticks;
+        // the rain and thunder timers are stopped meanwhile.
+        game.level.clear_weather_time -= 1;
+        if game.level.clear_weather_time > 0 {
+            return;
         }
     } else {
-        from
-    };
-
-    game.level.thunder_time -= 1;
-    to = if game.level.thunder_time <= 0 {
-        if game.level.thundering {
-            Weather::Clear
-        } else {
-            Weather::Thunder
+        // While it is not raining, `rain_time` counts down to the next
+        // rain; while it is not thundering, `thunder_time` counts down
+        // to the next thunderstorm. A timer which is not running, as in
+        // a newly created level, is restarted instead.
+        if game.level.rain_time <= 0 {
+            game.level.rain_time = random_duration(game, from);
+        }
+        if game.level.thunder_time <= 0 {
+            let thunder = if from == Weather::Thunder {
+                Weather::Thunder
+            } else {
+                Weather::Clear
+            };
+            game.level.thunder_time = random_duration(game, thunder);
         }
-    } else {
-        to
-    };
 
-    if from != to {
-        let duration = match to {
-            Weather::Clear => game
-                .rng()
-                .gen_range(TICKS_HALF_DAY, TICKS_WEEK + TICKS_HALF_DAY),
-            _ => game.rng().gen_range(TICKS_HALF_DAY, TICKS_DAY),
-        };
-        let event = WeatherChangeEvent { from, to, duration };
-        game.handle(world, event);
-        if event.to != from {
-            set_weather(game, event.to, event.duration);
+        game.level.rain_time -= 1;
+        game.level.thunder_time -= 1;
+        if game.level.rain_time > 0 && game.level.thunder_time > 0 {
+            return;
         }
     }
+
+    let to = from.next(&mut *game.rng());
+    let duration = random_duration(game, to);
+
+    let event = WeatherChangeEvent { from, to, duration };
+    game.handle(world, event);
+    set_weather(game, event.to, event.duration);
+}
+
+/// Returns a random duration, in ticks, for the given weather.
+fn random_duration(game: &Game, weather: Weather) -> i32 {
+    match weather {
+        Weather::Clear => game
+            .rng()
+            .gen_range(TICKS_HALF_DAY, TICKS_WEEK + TICKS_HALF_DAY),
+        _ => game.rng().gen_range(TICKS_HALF_DAY, TICKS_DAY),
+    }
 }
 
 pub fn get_weather(game: &Game) -> Weather {
@@ -78,19 +83,25 @@ pub fn set_weather(game: &mut Game, weather: Weather, duration: i32) -> Weather
     let from = get_weather(game);
     match weather {
         Weather::Rain => {
+            game.level.clear_weather_time = 0;
             game.level.raining = true;
             game.level.rain_time = duration;
+            game.level.thundering = false;
+            game.level.thunder_time = 0;
         }
         Weather::Thunder => {
+            game.level.clear_weather_time = 0;
+            game.level.raining = true;
+            game.level.rain_time = duration;
             game.level.thundering = true;
             game.level.thunder_time = duration;
         }
         Weather::Clear => {
+            game.level.clear_weather_time = duration;
             game.level.raining = false;
             game.level.rain_time = 0;
             game.level.thundering = false;
             game.level.thunder_time = 0;
-            game.level.clear_weather_time = duration;
         }
     };
     from
@@ -127,3 +138,83 @@ fn create_weather_packet(to: Weather) -> ChangeGameState {
         value: 0f32,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_test_framework::Test;
+
+    #[test]
+    fn update_weather_starts_timers() {
+        let mut test = Test::new();
+
+        test.run(update_weather);
+
+        assert_eq!(get_weather(&test.game), Weather::Clear);
+        assert!(test.game.level.rain_time > 0);
+        assert!(test.game.level.thunder_time > 0);
+    }
+
+    #[test]
+    fn update_weather_changes_when_rain_time_runs_out() {
+        let mut test = Test::new();
+        test.game.level.rain_time = 2;
+        test.game.level.thunder_time = 1000;
+
+        test.run(update_weather);
+        assert_eq!(get_weather(&test.game), Weather::Clear);
+
+        test.run(update_weather);
+        assert_ne!(get_weather(&test.game), Weather::Clear);
+        assert!(test.game.level.rain_time > 0);
+    }
+
+    #[test]
+    fn update_weather_changes_when_thunder_time_runs_out() {
+        let mut test = Test::new();
+        set_weather(&mut test.game, Weather::Rain, 1000);
+        test.game.level.thunder_time = 1;
+
+        test.run(update_weather);
+        assert_ne!(get_weather(&test.game), Weather::Rain);
+    }
+
+    #[test]
+    fn update_weather_waits_for_clear_weather_time() {
+        let mut test = Test::new();
+        test.game.level.clear_weather_time = 2;
+        test.game.level.rain_time = 1;
+
+        test.run(update_weather);
+        assert_eq!(test.game.level.clear_weather_time, 1);
+        assert_eq!(test.game.level.rain_time, 1);
+        assert_eq!(get_weather(&test.game), Weather::Clear);
+    }
+
+    #[test]
+    fn set_weather_clear_only_sets_clear_weather_time() {
+        let mut test = Test::new();
+        set_weather(&mut test.game, Weather::Thunder, 1000);
+
+        set_weather(&mut test.game, Weather::Clear, 500);
+        assert_eq!(test.game.level.clear_weather_time, 500);
+        assert_eq!(test.game.level.rain_time, 0);
+        assert_eq!(test.game.level.thunder_time, 0);
+        assert_eq!(get_weather(&test.game), Weather::Clear);
+    }
+
+    #[test]
+    fn update_weather_changes_after_clear_duration() {
+        let mut test = Test::new();
+        set_weather(&mut test.game, Weather::Clear, 5);
+
+        for _ in 0..4 {
+            test.run(update_weather);
+            assert_eq!(get_weather(&test.game), Weather::Clear);
+        }
+
+        test.run(update_weather);
+        assert_ne!(get_weather(&test.game), Weather::Clear);
+        assert_eq!(test.game.level.clear_weather_time, 0);
+    }
+}